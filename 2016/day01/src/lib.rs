@@ -0,0 +1,151 @@
+use aoc_common::input::single_line;
+use aoc_common::{visualize, Direction, ParseError, Point, Solution, Turn};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    #[test]
+    fn test_day1() {
+        // Following R2, L3 leaves you 2 blocks East and 3 blocks North, or 5 blocks away.
+        // R2, R2, R2 leaves you 2 blocks due South of your starting positionition, which is 2 blocks away.
+        // R5, L5, R5, R3 leaves you 12 blocks away.
+        let test1 = Day01::parse("R2, L3").unwrap();
+        assert_eq!(follow_directions(&test1), Point::new(2, 3));
+        let test2 = Day01::parse("R2, R2, R2").unwrap();
+        assert_eq!(follow_directions(&test2), Point::new(0, -2));
+    }
+
+    #[test]
+    fn test_day2() {
+        let test1 = Day01::parse("R8, R4, R4, R8").unwrap();
+        let result1 = first_location(&test1);
+        assert_eq!(result1, Point::new(4, 0));
+    }
+
+    #[test]
+    fn test_walk_frames() {
+        let test1 = Day01::parse("R8, R4, R4, R8").unwrap();
+        let (_, frames) = visualize::record(|| first_location(&test1));
+        // One frame per block walked, up to and including the repeat
+        assert_eq!(20, frames.len());
+        let last = frames.last().unwrap();
+        assert!(last.starts_with("Position: 4, 0"));
+        assert_eq!(1, last.matches('@').count());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day01::parse("R2, X3").unwrap_err();
+        assert_eq!((1, 5), (err.line, err.column));
+        assert_eq!("X3", err.text);
+        let err = Day01::parse("R2, L3x").unwrap_err();
+        assert_eq!((1, 6), (err.line, err.column));
+        assert_eq!("3x", err.text);
+    }
+}
+
+// Draws the blocks around the current position, with North at the top: '@'
+// is where we are now and '#' is everywhere we've already been.
+fn render_walk(position: Point, visited: &HashSet<Point>) -> String {
+    let mut lines = vec![format!("Position: {}, {}", position.x, position.y)];
+    for y in (position.y - 10..=position.y + 10).rev() {
+        let line: String = (position.x - 30..=position.x + 30)
+            .map(|x| {
+                let pt = Point::new(x, y);
+                if pt == position {
+                    '@'
+                } else if visited.contains(&pt) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+// NB: This is a copy + refactor of follow_directions that walks one block
+//     at a time, since every intermediate position needs to be checked.
+//     (Originally written with complex numbers, following Tobin's
+//     suggestion; it now uses the shared Point/Direction types.)
+fn first_location(directions: &[(Turn, i32)]) -> Point {
+    // East corresponds to +x, and North is +y.
+    let mut position = Point::ORIGIN;  // start at origin
+    let mut heading = Direction::North;  // start off heading N
+    
+    let mut visited = HashSet::new();
+    visited.insert(position);
+    
+    let mut found_repeat = false;
+
+    'outer: for &(turn, num_blocks) in directions {
+        heading = heading.turn(turn);
+        // NB: We're not checking for turning at the same location -- 
+        //     it needs to check every step.
+        for _ in 0..num_blocks {
+            position = position.step(heading);
+            visualize::frame(|| render_walk(position, &visited));
+            if visited.contains(&position) {
+                log::debug!("We already visited this position {:?}", position);
+                // TODO: I wanted to return the answer from the loop, but that 
+                //       seems to only be a thing in `loop` and not in `for` loops.
+                found_repeat = true;
+                break 'outer;
+            }
+            visited.insert(position);
+        }   
+    }
+    if !found_repeat {
+        panic!("No positionition visited twice! history = {:?}", visited);   
+    }
+    position
+}
+
+fn follow_directions(directions: &[(Turn, i32)]) -> Point {
+    let mut position = Point::ORIGIN;
+    let mut heading = Direction::North;
+    for &(turn, steps) in directions {
+        heading = heading.turn(turn);
+        position = position.moved(heading, steps);
+    }
+    position
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    // Each instruction is the direction to turn and how many blocks to walk.
+    type Input = Vec<(Turn, i32)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = single_line(input)?;
+        let mut directions = Vec::new();
+        for token in input.split(", ") {
+            let mut chars = token.chars();
+            let turn = chars.next().and_then(Turn::from_char).ok_or_else(|| {
+                ParseError::new(input, token, "expected L or R followed by a distance")
+            })?;
+            let blocks = chars.as_str();
+            let num_blocks = blocks
+                .parse::<i32>()
+                .map_err(|err| ParseError::new(input, blocks, err))?;
+            directions.push((turn, num_blocks));
+        }
+        Ok(directions)
+    }
+
+    fn part1(directions: &Self::Input) -> i32 {
+        follow_directions(directions).manhattan_distance(Point::ORIGIN)
+    }
+
+    fn part2(directions: &Self::Input) -> i32 {
+        let position = first_location(directions);
+        position.manhattan_distance(Point::ORIGIN)
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_common::{parse_lines, Direction, ParseError, Point, Solution};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    #[test]
+    fn test_part1() {
+        let input = String::from(
+            "ULL
+RRDDD
+LURDL
+UUUUD",
+        );
+        let answer = vec![1, 9, 8, 5];
+        let result = square_keypad(&Day02::parse(&input).unwrap());
+        assert_eq!(result, answer);
+    }
+
+    #[test]
+    fn test_part2() {
+        // QUESTION: How to get a shared variable within the tests module?
+        let input = String::from(
+            "ULL
+RRDDD
+LURDL
+UUUUD",
+        );
+        let answer = vec!['5', 'D', 'B', '3'];
+        let result = diamond_keypad(&Day02::parse(&input).unwrap());
+        assert_eq!(result, answer);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day02::parse("ULL\nRNL").unwrap_err();
+        assert_eq!((2, 2), (err.line, err.column));
+        assert_eq!("N", err.text);
+    }
+}
+
+// Follow each line of moves across the keypad, starting from '5', and
+// record which button we end up on. Moves that would leave the keypad are
+// ignored.
+fn press_buttons(keypad: &HashMap<Point, char>, input: &[Vec<Direction>]) -> Vec<char> {
+    let mut answer = Vec::<char>::new();
+    let mut pos = *keypad
+        .iter()
+        .find(|(_, &button)| button == '5')
+        .map(|(pt, _)| pt)
+        .unwrap();
+    for line in input {
+        for &direction in line {
+            let next = pos.step(direction);
+            if keypad.contains_key(&next) {
+                pos = next;
+            }
+        }
+        answer.push(keypad[&pos]);
+        log::debug!("Resulting button is: {}", keypad[&pos]);
+    }
+    answer
+}
+
+fn diamond_keypad(input: &[Vec<Direction>]) -> Vec<char> {
+    // The door lock's numbers are arranged like so:
+    //     1
+    //   2 3 4
+    // 5 6 7 8 9
+    //   A B C
+    //     D
+    // Centering the keypad on 7, the valid buttons are exactly the points
+    // within 2 steps of it.
+    let rows = ["  1  ", " 234 ", "56789", " ABC ", "  D  "];
+    let mut keypad = HashMap::new();
+    for (row, buttons) in rows.iter().enumerate() {
+        for (col, button) in buttons.chars().enumerate() {
+            let pt = Point::new(col as i32 - 2, 2 - row as i32);
+            if pt.manhattan_distance(Point::ORIGIN) <= 2 {
+                keypad.insert(pt, button);
+            }
+        }
+    }
+    press_buttons(&keypad, input)
+}
+
+fn square_keypad(input: &[Vec<Direction>]) -> Vec<i32> {
+    // The door lock's numbers are arranged like so:
+    // 1 2 3
+    // 4 5 6
+    // 7 8 9
+    // Start on five, then follow L/R/U/D directions for each line in input
+    // Five is at the origin, so the keypad spans (-1, -1) to (1, 1).
+    let mut keypad = HashMap::new();
+    for num in 1..10 {
+        let pt = Point::new((num - 1) % 3 - 1, 1 - (num - 1) / 3);
+        keypad.insert(pt, std::char::from_digit(num as u32, 10).unwrap());
+    }
+    press_buttons(&keypad, input)
+        .iter()
+        .map(|button| button.to_digit(10).unwrap() as i32)
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    // One line of L/R/U/D moves for each button in the code
+    type Input = Vec<Vec<Direction>>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            line.char_indices()
+                .map(|(idx, ch)| {
+                    Direction::from_udlr(ch).ok_or_else(|| {
+                        let text = &line[idx..idx + ch.len_utf8()];
+                        ParseError::new(line, text, "expected one of U, D, L or R")
+                    })
+                })
+                .collect()
+        })
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        square_keypad(input).iter().map(|num| num.to_string()).collect()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        diamond_keypad(input).iter().collect()
+    }
+}
+
@@ -0,0 +1,62 @@
+use aoc_common::input::{parse_lines, parse_numbers};
+use aoc_common::{ParseError, Solution};
+
+fn is_triangle(a: i32, b: i32, c: i32) -> bool {
+    let sum = a + b + c;
+    2*a < sum && 2*b < sum && 2*c < sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    // Each row of the input has three numbers
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let row = parse_numbers(line)?;
+            if row.len() != 3 {
+                return Err(ParseError::new(line, line, "expected three side lengths"));
+            }
+            Ok(row)
+        })
+    }
+
+    // Triangles specified per-line
+    fn part1(rows: &Self::Input) -> i32 {
+        let mut count = 0;
+        for sides in rows {
+            // TODO: Is there a way to immediately assign the vector to a,b,c?
+            let a = sides[0];
+            let b = sides[1];
+            let c = sides[2];
+            if is_triangle(a, b, c) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // Triangles are in columns
+    // I wish I could find an equivalent of np.loadtxt()...
+    // Instead, this is a kind of ugly way of reading the input three times,
+    // each time handling a different column.
+    fn part2(rows: &Self::Input) -> i32 {
+        let mut data = Vec::<i32>::new();
+        for idx in 0..3 {
+            for row in rows {
+                data.push(row[idx]);
+            }
+        }
+        let mut count = 0;
+        for idx in (0..data.len()).step_by(3) {
+            if is_triangle(data[idx], data[idx+1], data[idx+2]) {
+                count += 1;
+            }
+
+        }
+        count
+    }
+}
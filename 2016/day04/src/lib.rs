@@ -0,0 +1,131 @@
+use aoc_common::{parse_lines, Counter, ParseError, Solution};
+use regex::Regex;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    /* From the instructions:
+    A room is real (not a decoy) if the checksum is the five most common letters in the encrypted name, in order, with ties broken by alphabetization. For example:
+
+    aaaaa-bbb-z-y-x-123[abxyz] is a real room because the most common letters are a (5), b (3), and then a tie between x, y, and z, which are listed alphabetically.
+    a-b-c-d-e-f-g-h-987[abcde] is a real room because although the letters are all tied (1 of each), the first five are listed alphabetically.
+    not-a-real-room-404[oarel] is a real room.
+    totally-real-room-200[decoy] is not.
+    Of the real rooms from the list above, the sum of their sector IDs is 1514.
+    */
+
+    #[test]
+    fn test() {
+        let input1 = String::from("aaaaa-bbb-z-y-x-123[abxyz]");
+        let room1: Room = Room::from(&input1).unwrap();
+        assert_eq!(room1.sector_id, 123);
+        assert_eq!(room1.input_checksum, "abxyz");
+        assert!(room1.is_real_room());
+
+        let input2 = "a-b-c-d-e-f-g-h-987[abcde]";
+        let room2 = Room::from(input2).unwrap();
+        assert!(room2.is_real_room());
+
+        let input3 = "not-a-real-room-404[oarel]";
+        let room3 = Room::from(input3).unwrap();
+        assert!(room3.is_real_room());
+
+        let input4 = "totally-real-room-200[decoy]";
+        let room4 = Room::from(input4).unwrap();
+        assert!(!room4.is_real_room());
+    }
+}
+
+pub struct Room {
+    pub encrypted_name: String,
+    // The decrypted name, with dashes replaced by spaces.
+    pub name: String,
+    pub sector_id: u32,
+    pub input_checksum: String,
+    pub calculated_checksum: String,
+}
+
+impl Room {
+    pub fn from(input: &str) -> Result<Room, ParseError> {
+        let re = Regex::new(r"^([a-z][\-[a-z]*]*)-([0-9]*)\[([a-z]*)\]$").unwrap();
+        let cap = re.captures(input).ok_or_else(|| {
+            ParseError::new(input, input, "expected encrypted-name-123[abcde]")
+        })?;
+
+        let letters: Counter<char> = cap[1].chars().filter(|ch| *ch != '-').collect();
+        // The checksum is the five most common letters; Counter breaks ties
+        // alphabetically, just like the puzzle wants.
+        let cs: String = letters.most_common(5).into_iter().map(|(ch, _)| *ch).collect();
+
+        // Decrypt the room name by rotating all characters in the encrypted
+        // name by the sector_id.  Dashes become spaces.
+        let encrypted_name = String::from(&cap[1]);
+        let sector_id = cap[2]
+            .parse::<u32>()
+            .map_err(|err| ParseError::new(input, cap.get(2).unwrap().as_str(), err))?;
+        let input_cs = String::from(&cap[3]);
+        let mut name = String::new();
+        // Ugh. This is much easier in languages that don't have proper unicode
+        // support and would let me just call ord() on a charcter.
+        for ch in encrypted_name.chars() {
+            if ch == '-' {
+                name.push(' ');
+            }
+            if ch.is_ascii_lowercase() {
+                // lowercase ascii runs from 97 ('a') to 122 ('z').
+                // Output letter = (((input - 97) + shift) % 26) + 97
+                let output_ord = (((ch as u32 - 97) + sector_id) % 26) + 97;
+                // Is this as ugly as it seems?  Will these conversions ever fail?
+                name.push(output_ord as u8 as char);
+            }
+        }
+
+        Ok(Room {
+            encrypted_name,
+            name,
+            sector_id,
+            input_checksum: input_cs,
+            calculated_checksum: cs,
+        })
+    }
+
+    // NB: &self is syntactic sugar for 'self: &Room'
+    pub fn is_real_room(&self) -> bool {
+        self.input_checksum == self.calculated_checksum
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Room>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Room::from)
+    }
+
+    // What is the sum of the sector IDs of the real rooms?
+    fn part1(rooms: &Self::Input) -> u32 {
+        let mut sum = 0;
+        for room in rooms {
+            if room.is_real_room() {
+                sum += room.sector_id;
+            }
+        }
+        sum
+    }
+
+    fn part2(rooms: &Self::Input) -> u32 {
+        let mut id = 0;
+        for room in rooms {
+            if room.is_real_room() && room.name.contains("object") {
+                id = room.sector_id;
+                break;
+            }
+        }
+        id
+    }
+}
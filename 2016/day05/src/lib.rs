@@ -0,0 +1,77 @@
+use aoc_common::input::single_line;
+use aoc_common::{ParseError, Solution};
+use std::collections::BTreeMap;
+
+// This is kind of screaming to be made a generator, rather than having to be
+// called with the information required to resume.
+// It looks like in rust, you'd do this by creating a Struct that implements
+// a next() function within an `impl Iterator for MyRange`.
+// You'd have to explicitly track the index (repeated
+// calls won't automatically have the saved state).
+// I'm going to punt on that for now since I haven't gotten that far in
+// the rust book.
+fn find_next_character(prefix: &str, index: i32) -> (char, char, i32) {
+    let mut count = index;
+    loop {
+        aoc_common::cancel::check();
+        let input = format!("{}{}", prefix, count);
+        if count % 10_000 == 0 {
+            log::trace!("Testing hash of {}", input);
+        }
+        let hash = md5::compute(&input);
+        // convert from md5::Digest to String
+        let hash_string = format!("{:x}", hash);
+        if hash_string.starts_with("00000") {
+            let hash_chars: Vec<char> = hash_string.chars().collect();
+            log::debug!(
+                "Found next character in password: {}, {}",
+                hash_chars[5], hash_chars[6]
+            );
+            break (hash_chars[5], hash_chars[6], count);
+        }
+        count += 1;
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    // The door ID
+    type Input = String;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(single_line(input)?.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        let mut password = String::new();
+        let mut start_idx = -1;
+        for _ in 0..8 {
+            start_idx += 1;
+            let (cc, _, ii) = find_next_character(input, start_idx);
+            password.push(cc);
+            start_idx = ii;
+        }
+        password
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        let mut password_map = BTreeMap::<u32, char>::new();
+        let mut count = 0;
+        while password_map.len() < 8 {
+            let (c1, c2, input_idx) = find_next_character(input, count);
+            let password_idx = c1.to_digit(16).unwrap();
+            if password_idx < 8 && !password_map.contains_key(&password_idx) {
+                password_map.insert(password_idx, c2);
+            }
+            count = input_idx + 1;
+        }
+        let mut password = String::new();
+        for (_, &val) in password_map.iter() {
+            password.push(val);
+        }
+        password
+    }
+}
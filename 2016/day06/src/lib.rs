@@ -0,0 +1,50 @@
+use aoc_common::input::char_grid;
+use aoc_common::{Counter, ParseError, Solution};
+
+fn get_columns(rows: &[Vec<char>]) -> Vec<String> {
+    let mut columns = Vec::<String>::new();
+    for row in rows {
+        for (idx, &ch) in row.iter().enumerate() {
+            if columns.len() <= idx {
+                columns.push(String::new());
+            }
+            columns[idx].push(ch);
+        }
+    }
+    columns
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    // The message characters, transposed into columns
+    type Input = Vec<String>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(get_columns(&char_grid(input)?))
+    }
+
+    // Figure out what the most common character is for each column.
+    fn part1(columns: &Self::Input) -> String {
+        let mut password = String::new();
+        for column in columns {
+            let counts: Counter<char> = column.chars().collect();
+            let (next_letter, _) = counts.most_common(1)[0];
+            password.push(*next_letter);
+        }
+        password
+    }
+
+    // Figure out what the least common character is for each column.
+    fn part2(columns: &Self::Input) -> String {
+        let mut password = String::new();
+        for column in columns {
+            let counts: Counter<char> = column.chars().collect();
+            let (next_letter, _) = counts.least_common(1)[0];
+            password.push(*next_letter);
+        }
+        password
+    }
+}
@@ -0,0 +1,198 @@
+use aoc_common::{parse_lines, visualize, ParseError, Solution};
+use ndarray::prelude::*;
+use regex::Regex;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_rect1() {
+        // Primitive arrays are annoyingly limited. I can't figure out how 
+        // to pass one with undefined size as a parameter to a function. 
+        // So, trying out the ndarray library.
+        let mut screen = Array2::<i32>::zeros((3, 7));
+        let answer = array![
+            [1, 1, 1, 0, 0, 0, 0],
+            [1, 1, 1, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0]
+        ];
+        update_screen(&mut screen, &Day08::parse("rect 3x2").unwrap());
+        assert_eq!(screen, answer);
+    }
+
+    #[test]
+    fn test_rotate_column1() {
+        let mut screen = array![
+            [1, 1, 1, 0, 0, 0, 0],
+            [1, 1, 1, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0]
+        ];
+        let answer = array![
+            [1, 0, 1, 0, 0, 0, 0],
+            [1, 1, 1, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0]
+        ];
+        update_screen(&mut screen, &Day08::parse("rotate column x=1 by 1").unwrap());
+        assert_eq!(screen, answer);
+    }
+
+    #[test]
+    fn test_rotate_row1() {
+        let mut screen = array![
+            [1, 0, 1, 0, 0, 0, 0],
+            [1, 1, 1, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0]
+        ];
+        let answer = array![
+            [0, 0, 0, 0, 1, 0, 1],
+            [1, 1, 1, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0]
+        ];
+        update_screen(&mut screen, &Day08::parse("rotate row y=0 by 4").unwrap());
+        assert_eq!(screen, answer);
+    }
+
+    #[test]
+    fn test_rotate_col2() {
+        let mut screen = array![
+            [0, 0, 0, 0, 1, 0, 1],
+            [1, 1, 1, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0]
+        ];
+        let answer = array![
+            [0, 1, 0, 0, 1, 0, 1],
+            [1, 0, 1, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0]
+        ];
+        update_screen(&mut screen, &Day08::parse("rotate column x=1 by 1").unwrap());
+        assert_eq!(screen, answer);
+    }
+
+    #[test]
+    fn test_frames() {
+        let operations = Day08::parse(include_str!("../fixtures/example.txt")).unwrap();
+        let mut screen = Array2::<i32>::zeros((3, 7));
+        let (_, frames) = visualize::record(|| update_screen(&mut screen, &operations));
+        // One frame after each operation
+        assert_eq!(operations.len(), frames.len());
+        assert_eq!("###    \n###    \n       ", frames[0]);
+        assert_eq!(&render_screen(&screen), frames.last().unwrap());
+    }
+}
+
+pub enum Operation {
+    // "rect AxB" turns on all of the pixels in a rectangle at the top-left
+    // of the screen which is A wide and B tall.
+    Rect { ncols: i32, nrows: i32 },
+    // "rotate row y=A by B" shifts all of the pixels in row A 
+    // (0 is the top row) right by B pixels. Pixels that would fall off 
+    // the right end appear at the left end of the row.
+    RotateRow { row: i32, npixels: i32 },
+    // "rotate column x=A by B" shifts all of the pixels in column A 
+    // (0 is the left column) down by B pixels. Pixels that would fall off 
+    // the bottom appear at the top of the column.
+    RotateColumn { col: i32, npixels: i32 },
+}
+
+// Parse the captured number in the given group of a line that matched one of
+// the operation regexes.
+fn parse_capture(line: &str, captures: &regex::Captures, idx: usize) -> Result<i32, ParseError> {
+    let text = captures.get(idx).unwrap().as_str();
+    text.parse::<i32>().map_err(|err| ParseError::new(line, text, err))
+}
+
+pub fn parse_operations(input: &str) -> Result<Vec<Operation>, ParseError> {
+    let re_rect = Regex::new(r"^rect ([0-9]+)x([0-9]+)$").unwrap();
+    let re_row = Regex::new(r"^rotate row y=([0-9]+) by ([0-9]+)$").unwrap();
+    let re_col = Regex::new(r"^rotate column x=([0-9]+) by ([0-9]+)$").unwrap();
+
+    parse_lines(input, |line| {
+        if let Some(captures) = re_rect.captures(line) {
+            let ncols = parse_capture(line, &captures, 1)?;
+            let nrows = parse_capture(line, &captures, 2)?;
+            Ok(Operation::Rect { ncols, nrows })
+        } else if let Some(captures) = re_row.captures(line) {
+            let row = parse_capture(line, &captures, 1)?;
+            let npixels = parse_capture(line, &captures, 2)?;
+            Ok(Operation::RotateRow { row, npixels })
+        } else if let Some(captures) = re_col.captures(line) {
+            let col = parse_capture(line, &captures, 1)?;
+            let npixels = parse_capture(line, &captures, 2)?;
+            Ok(Operation::RotateColumn { col, npixels })
+        } else {
+            Err(ParseError::new(line, line, "unrecognized operation"))
+        }
+    })
+}
+
+pub fn update_screen(screen: &mut Array2<i32>, operations: &[Operation]) {
+    for operation in operations {
+        match *operation {
+            Operation::Rect { ncols, nrows } => {
+                let rect = Array2::<i32>::ones((nrows as usize, ncols as usize));
+                screen.slice_mut(s![..nrows, ..ncols]).assign(&rect);
+            }
+            Operation::RotateRow { row, npixels } => {
+                let new_row = ndarray::stack![
+                    Axis(0),
+                    screen.slice(s![row, -npixels..]),
+                    screen.slice(s![row, ..-npixels])
+                ];
+                screen.slice_mut(s![row, ..]).assign(&new_row);
+            }
+            Operation::RotateColumn { col, npixels } => {
+                let new_col = ndarray::stack![
+                    Axis(0),
+                    screen.slice(s![-npixels.., col]),
+                    screen.slice(s![..-npixels, col])
+                ];
+                screen.slice_mut(s![.., col]).assign(&new_col);
+            }
+        }
+        visualize::frame(|| render_screen(screen));
+    }
+}
+
+pub fn render_screen(screen: &Array2<i32>) -> String {
+    let mut rows = Vec::new();
+    for row in screen.outer_iter() {
+        let mut rr = String::new();
+        for elem in row.iter() {
+            if *elem == 0 {
+                rr.push(' ');
+            } else {
+                rr.push('#');
+            }
+        }
+        rows.push(rr);
+    }
+    rows.join("\n")
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Operation>;
+    type Answer1 = i32;
+    // The screen, drawn with '#' for every lit pixel
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_operations(input)
+    }
+
+    fn part1(operations: &Self::Input) -> i32 {
+        // After simulating the screen, count up how many characters should be lit.
+        let mut screen = Array2::<i32>::zeros((6, 50));
+        update_screen(&mut screen, operations);
+        screen.sum()
+    }
+
+    fn part2(operations: &Self::Input) -> String {
+        let mut screen = Array2::<i32>::zeros((6, 50));
+        update_screen(&mut screen, operations);
+        render_screen(&screen)
+    }
+}
@@ -0,0 +1,197 @@
+use aoc_common::input::single_line;
+use aoc_common::{ParseError, Solution};
+use regex::Regex;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_part1() {
+        // ADVENT contains no markers and decompresses to itself with no changes, 
+        // resulting in a decompressed length of 6.
+        let input1 = "ADVENT";
+        let output1 = decompress(input1);
+        assert_eq!(input1, output1);
+        
+        // A(1x5)BC repeats only the B a total of 5 times, becoming ABBBBBC for 
+        // a decompressed length of 7.
+        let input2 = "A(1x5)BC";
+        let output2 = decompress(input2);
+        assert_eq!("ABBBBBC", output2);
+
+        // (3x3)XYZ becomes XYZXYZXYZ for a decompressed length of 9.
+        let input3 = "(3x3)XYZ";
+        let output3 = decompress(input3);
+        assert_eq!("XYZXYZXYZ", output3);
+
+        // A(2x2)BCD(2x2)EFG doubles the BC and EF, becoming ABCBCDEFEFG for a 
+        // decompressed length of 11.
+        let input4 = "A(2x2)BCD(2x2)EFG";
+        let output4 = decompress(input4);
+        assert_eq!("ABCBCDEFEFG", output4);
+
+        // (6x1)(1x3)A simply becomes (1x3)A - the (1x3) looks like a marker, 
+        // but because it's within a data section of another marker, it is not 
+        // treated any differently from the A that comes after it. 
+        // It has a decompressed length of 6.
+        let input5 = "(6x1)(1x3)A";
+        let output5 = decompress(input5);
+        assert_eq!("(1x3)A", output5);
+
+        // X(8x2)(3x3)ABCY becomes X(3x3)ABC(3x3)ABCY (for a decompressed 
+        // length of 18), because the decompressed data from the (8x2) marker 
+        // (the (3x3)ABC) is skipped and not processed further.
+        let input6 = "X(8x2)(3x3)ABCY";
+        let output6 = decompress(input6);
+        assert_eq!("X(3x3)ABC(3x3)ABCY", output6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day09::parse("AB(3x3XYZ").unwrap_err();
+        assert_eq!((1, 3), (err.line, err.column));
+        assert_eq!("(3x3XYZ", err.text);
+
+        let err = Day09::parse("A(1x5)BC(4x2)XY").unwrap_err();
+        assert_eq!((1, 9), (err.line, err.column));
+        assert_eq!("(4x2)", err.text);
+
+        let err = Day09::parse("(99999999999999999999x2)A").unwrap_err();
+        assert_eq!("(99999999999999999999x2)", err.text);
+
+        // Markers inside another marker's data are just text to part 1, and
+        // part 2 treats them the same way when they don't fit.
+        let input = Day09::parse("(5x1)(1x3)A\n").unwrap();
+        assert_eq!(6, Day09::part1(&input));
+        assert_eq!(6, Day09::part2(&input));
+    }
+}
+
+// The marker at the start of `data`, if there's one whose data fits in the
+// rest of it, as (length of the marker, number of characters to repeat,
+// number of repeats).
+fn marker(data: &[char]) -> Option<(usize, usize, usize)> {
+    // Reads the number at data[*idx..], leaving idx just past it.
+    let number = |idx: &mut usize| {
+        let start = *idx;
+        while data.get(*idx).is_some_and(|ch| ch.is_ascii_digit()) {
+            *idx += 1;
+        }
+        data[start..*idx].iter().collect::<String>().parse::<usize>().ok()
+    };
+    if data.first() != Some(&'(') {
+        return None;
+    }
+    let mut idx = 1;
+    let nchars = number(&mut idx)?;
+    if data.get(idx) != Some(&'x') {
+        return None;
+    }
+    idx += 1;
+    let nreps = number(&mut idx)?;
+    if data.get(idx) != Some(&')') {
+        return None;
+    }
+    let marker_len = idx + 1;
+    if nchars > data.len() - marker_len {
+        return None;
+    }
+    Some((marker_len, nchars, nreps))
+}
+
+// Makes sure that every marker is well-formed and that the data it repeats
+// fits in the rest of the input, so that `marker` always finds them.
+// Markers inside another marker's data aren't checked, since part 1 treats
+// them as plain text.
+fn check_markers(input: &str) -> Result<(), ParseError> {
+    let re = Regex::new(r"^\(([0-9]+)x([0-9]+)\)").unwrap();
+    let mut rest = input;
+    while let Some(start) = rest.find('(') {
+        rest = &rest[start..];
+        let Some(cap) = re.captures(rest) else {
+            let end = rest.find(')').map_or(rest.len(), |end| end + 1);
+            return Err(ParseError::new(input, &rest[..end], "expected a marker like (3x2)"));
+        };
+        let text = cap.get(0).unwrap().as_str();
+        let nchars = cap[1].parse::<usize>().map_err(|err| ParseError::new(input, text, err))?;
+        cap[2].parse::<usize>().map_err(|err| ParseError::new(input, text, err))?;
+        let data = &rest[text.len()..];
+        let num_left = data.chars().count();
+        if nchars > num_left {
+            let msg = format!("repeats {} characters, but only {} are left", nchars, num_left);
+            return Err(ParseError::new(input, text, msg));
+        }
+        let end = data.char_indices().nth(nchars).map_or(data.len(), |(end, _)| end);
+        rest = &data[end..];
+    }
+    Ok(())
+}
+
+pub fn decompress(input: &str) -> String {
+    let data: Vec<char> = input.chars().collect();
+    let mut output = String::new();
+    let mut idx = 0;
+    while idx < data.len() {
+        if let Some((marker_len, nchars, nreps)) = marker(&data[idx..]) {
+            // Update idx to point past the marker
+            idx += marker_len;
+            for _ in 0..nreps {
+                for ii in 0..nchars {
+                    output.push(data[idx + ii]);
+                }
+            }
+            idx += nchars;
+        } else {
+            output.push(data[idx]);
+            idx += 1;
+        }
+    }
+    output
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    // The compressed file
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = single_line(input)?;
+        check_markers(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let result = decompress(input);
+        result.len()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2_helper(input)
+    }
+}
+
+fn part2_helper(input: &str) -> usize {
+    let mut len: usize = 0;  // Cumulative length of this (sub)string
+    let mut idx = 0;  // Index into this (sub)string
+    let data: Vec<char> = input.chars().collect();
+    while idx < data.len() {
+        if let Some((marker_len, nchars, nreps)) = marker(&data[idx..]) {
+            let start_idx = idx + marker_len;
+            let end_idx = start_idx + nchars;
+            
+            let substr: String = data[start_idx..end_idx].iter().collect();
+            len += nreps * part2_helper(&substr);
+
+            idx = end_idx;
+        } else {
+            len += 1;
+            idx += 1;
+        }
+    }
+    len
+}
@@ -0,0 +1,324 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_initialization() {
+        let test_input = include_str!("../fixtures/example.txt");
+
+        // Initially, bot 1 starts with a value-3 chip, and bot 2 starts 
+        // with a value-2 chip and a value-5 chip.
+        let factory = parse_input(test_input).unwrap();
+        let bot1_chips: HashSet<i32> = vec![3].into_iter().collect();
+        let bot2_chips: HashSet<i32> = vec![2,5].into_iter().collect();
+        assert_eq!(bot1_chips, factory.robots[&1].chips);
+        assert_eq!(bot2_chips, factory.robots[&2].chips);
+    
+        // bot 1 gives low to output 1 and high to bot 0
+        let bot1_low = Some(Action{dest: Destination::Output, id: 1});
+        let bot1_high = Some(Action{dest: Destination::Robot, id:0});
+        assert_eq!(bot1_low, factory.robots[&1].low);
+        assert_eq!(bot1_high, factory.robots[&1].high);
+    }    
+
+    #[test]
+    fn test_steps() {
+        let test_input = include_str!("../fixtures/example.txt");
+        let mut factory = parse_input(test_input).unwrap();
+
+        // Because bot 2 has two microchips, it gives its lower one (2) to 
+        // bot 1 and its higher one (5) to bot 0.
+        factory.step();
+        let bot0_step1: HashSet<i32> = vec![5].into_iter().collect();
+        let bot1_step1: HashSet<i32> = vec![2, 3].into_iter().collect();
+        let bot2_step1: HashSet<i32> = vec![].into_iter().collect();
+        assert_eq!(bot0_step1, factory.robots[&0].chips);
+        assert_eq!(bot1_step1, factory.robots[&1].chips);
+        assert_eq!(bot2_step1, factory.robots[&2].chips);
+
+        // Then, bot 1 has two microchips; it puts the value-2 chip in 
+        // output 1 and gives the value-3 chip to bot 0.
+        factory.step();
+        let bot0_step2: HashSet<i32> = vec![5, 3].into_iter().collect();
+        let bot1_step2: HashSet<i32> = vec![].into_iter().collect();
+        assert_eq!(2, factory.outputs[&1].unwrap());
+        assert_eq!(bot0_step2, factory.robots[&0].chips);
+        assert_eq!(bot1_step2, factory.robots[&1].chips);
+        assert_eq!(bot2_step1, factory.robots[&2].chips);  // hasn't changed...
+
+        // Finally, bot 0 has two microchips; it puts the 3 in output 2 
+        // and the 5 in output 0.
+        // In the end, output bin 0 contains a value-5 microchip, output 
+        // bin 1 contains a value-2 microchip, and output bin 2 contains 
+        // a value-3 microchip. 
+        factory.step();
+        assert_eq!(5, factory.outputs[&0].unwrap());
+        assert_eq!(2, factory.outputs[&1].unwrap());  // hasn't changed.
+        assert_eq!(3, factory.outputs[&2].unwrap());
+    }
+
+    #[test]
+    fn test_part1() {
+        // In this configuration, bot number 2 is responsible for comparing 
+        // value-5 microchips with value-2 microchips.
+        // TODO: print this out, then actually compare them... 
+        // Each step should only do one comparison, returning an 
+        // option of which robot moved which microchips.
+        let test_input = include_str!("../fixtures/example.txt");
+        let test_chips: HashSet<i32> = [5, 2].iter().cloned().collect();
+
+        let robot_id = find_comparison(&parse_input(test_input).unwrap(), &test_chips);
+        assert_eq!(2, robot_id);
+
+    }
+}
+
+#[derive(Clone)]
+pub struct Factory {
+    // Robot maps ID to list of chips currently carried
+    robots: HashMap<i32, Robot>,
+    outputs: HashMap<i32, Option<i32>>,
+}
+
+impl Factory {
+    // Value of the chip that ended up in the given output bin, if any.
+    pub fn get_output(&self, id: i32) -> Option<i32> {
+        self.outputs.get(&id).cloned().flatten()
+    }
+
+    fn new() -> Factory {
+        Factory {
+            robots: HashMap::new(),
+            outputs: HashMap::new(),
+        }
+    }
+
+    // I broke this out into another function while fighting the borrow checker.
+    fn get_donee(&self) -> Option<i32> {
+        let mut donor_id = None;
+        for (id, robot) in self.robots.iter() {
+            if robot.chips.len() == 2 {
+                log::debug!("Robot {} is giving away chips {:?}", id, robot.chips);
+                donor_id = Some(*id);
+                break;
+            }
+        }
+        donor_id
+    }   
+
+    fn get_min_max(&self, id: i32) -> (i32, i32) {
+        let robot = self.robots.get(&id).unwrap();
+        let min = robot.chips.iter().min().unwrap();
+        let max = robot.chips.iter().max().unwrap();
+        (*min, *max)
+    }
+
+    fn clear_robot(&mut self, id: i32) {
+        let robot = self.robots.get_mut(&id).unwrap();
+        robot.chips = HashSet::new();
+    }
+
+    // Helper functions created while fighting the borrow checker...
+    fn get_low(&self, id:i32) -> Action {
+        let robot = self.robots.get(&id).unwrap();
+        robot.low.clone().unwrap()
+    }
+
+    fn get_high(&self, id:i32) -> Action {
+        let robot = self.robots.get(&id).unwrap();
+        robot.high.clone().unwrap()
+    }
+
+    // Has the first robot holding two chips hand them off, returning what
+    // it did, or None if no robot could act.
+    pub fn step(&mut self) -> Option<StepResult> {
+        log::trace!("Called step!");
+        // TODO: WIthin the loop, figure out what action should be taken,
+        // THen actually modify it afterwards.
+        let donor_id = self.get_donee();
+
+        if let Some(id) = donor_id {
+            let (min, max) = self.get_min_max(id);
+            let low = self.get_low(id);
+            match &low.dest {
+                Destination::Robot => {
+                    log::trace!("...Trying to give chip {} to robot {}", min, low.id);
+                    let robot = self.robots.entry(low.id).or_insert(Robot::new());
+                    robot.chips.insert(min);
+                    //self.robots.get_mut(&low.id)?.chips.insert(min);
+                }, 
+                Destination::Output => {
+                    log::trace!("...Trying to put chip {} in output {}", min, low.id);
+                    let output = self.outputs.entry(low.id).or_insert(None);
+                    *output = Some(min);
+                    //self.outputs.get_mut(&low.id)?.chips.insert(min);
+                },
+            }
+            let high = self.get_high(id);
+            match &high.dest {
+                Destination::Robot => {
+                    log::trace!("...Trying to give chip {} to robot {}", max, high.id);
+                    let robot = self.robots.entry(high.id).or_insert(Robot::new());
+                    robot.chips.insert(max);
+                    // self.robots.get_mut(&high.id)?.chips.insert(max);
+                }
+                Destination::Output => {
+                    log::trace!("...Trying to put chip {} in output {}", max, high.id);
+                    let output = self.outputs.entry(high.id).or_insert(None);
+                    *output = Some(max);
+                    //self.outputs.get_mut(&high.id)?.chips.insert(max);
+                }
+            }
+            
+            // Both chips have been given away...
+            self.clear_robot(id);
+
+            let sr = StepResult{robot_id: id, chips: vec![min, max].into_iter().collect()};
+            return Some(sr);
+
+        }
+        
+        None
+    }
+}
+
+// Type representing what action was taken in a given turn
+#[derive(Debug)]
+pub struct StepResult {
+    pub robot_id: i32,
+    pub chips: HashSet<i32>,   
+}
+
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+enum Destination {
+    Robot,
+    Output,
+}
+
+// Type representing an action that the robot can take.
+#[derive(Debug, Clone)]
+#[derive(PartialEq)]
+struct Action {
+    dest: Destination,
+    id: i32,
+}
+
+#[derive(Debug, Clone)]
+struct Robot {
+    chips: HashSet<i32>,
+    // TODO: I really don't like making this an option just to support partially-initialized robots.
+    low: Option<Action>,
+    high: Option<Action>,
+}
+
+impl Robot {
+    fn new() -> Robot {
+        Robot { 
+            chips: HashSet::new(),
+            low: None,
+            high: None,
+        }
+    }
+}
+
+// Parse a captured number from a line that matched one of the regexes.
+fn parse_capture(line: &str, cap: &regex::Captures, idx: usize) -> Result<i32, ParseError> {
+    let text = cap.get(idx).unwrap().as_str();
+    text.parse::<i32>().map_err(|err| ParseError::new(line, text, err))
+}
+
+fn parse_action(line: &str, cap: &regex::Captures, idx: usize) -> Result<Action, ParseError> {
+    let id = parse_capture(line, cap, idx + 1)?;
+    let dest_text = cap.get(idx).unwrap().as_str();
+    let dest = match dest_text {
+        "bot" => Destination::Robot,
+        "output" => Destination::Output,
+        _ => return Err(ParseError::new(line, dest_text, "unrecognized destination")),
+    };
+    Ok(Action{dest, id})
+}
+
+pub fn parse_input(input: &str) -> Result<Factory, ParseError> {
+    let mut factory = Factory::new();
+    let re_value = Regex::new(r"^value ([0-9]+) goes to bot ([0-9]+)$").unwrap();
+    let re_rules = Regex::new(r"^bot ([0-9]+) gives low to ([a-z]+) ([0-9]+) and high to ([a-z]+) ([0-9]+)$").unwrap();
+    parse_lines(input, |line| {
+        if let Some(cap) = re_value.captures(line) {
+            let chip_id = parse_capture(line, &cap, 1)?;
+            let robot_id = parse_capture(line, &cap, 2)?;
+            let robot = factory.robots.entry(robot_id).or_insert(Robot::new());
+            robot.chips.insert(chip_id);
+        } else if let Some(cap) = re_rules.captures(line) {
+            let robot_id = parse_capture(line, &cap, 1)?;
+            let low = parse_action(line, &cap, 2)?;
+            let high = parse_action(line, &cap, 4)?;
+            let robot = factory.robots.entry(robot_id).or_insert(Robot::new());
+            robot.low = Some(low);
+            robot.high = Some(high);
+        } else {
+            return Err(ParseError::new(line, line, "unrecognized instruction"));
+        }
+        Ok(())
+    })?;
+    Ok(factory)
+}
+
+// Find which robot compares the given pair of chips
+pub fn find_comparison(factory: &Factory, pair: &HashSet<i32>) -> i32 {
+    let mut factory = factory.clone();
+    loop {
+        let result = factory.step();
+        if let Some(action) = result {
+            if action.chips == *pair {
+                return action.robot_id;
+            }
+        } else {
+            break; 
+        }
+    }
+    panic!("Never found chips {:?}", pair);
+}
+
+// Run the factory to completion, then multiply the values in outputs 0, 1 and 2
+pub fn multiply_outputs(factory: &Factory) -> i32 {
+    let mut factory = factory.clone();
+    let mut result = Some(StepResult{robot_id: -1, chips: HashSet::new()});
+    while result.is_some() {
+        result = factory.step();
+        log::trace!("Result: {:?}", result);
+    }
+    let mut product = 1;
+    log::info!("Final factory outputs: {:?}", factory.outputs);
+    product *= factory.get_output(0).unwrap();
+    product *= factory.get_output(1).unwrap();
+    product *= factory.get_output(2).unwrap();
+    product
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Factory;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(factory: &Self::Input) -> i32 {
+        let pair: HashSet<i32> = [17, 61].iter().cloned().collect();
+        find_comparison(factory, &pair)
+    }
+
+    fn part2(factory: &Self::Input) -> i32 {
+        multiply_outputs(factory)
+    }
+}
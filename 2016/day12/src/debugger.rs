@@ -0,0 +1,380 @@
+// An interactive debugger for assembunny programs, for when one isn't doing
+// what it should. It reads one command per line (from stdin, when it's run
+// with `cargo run -- --debug`) and stops whenever it hits a breakpoint or a
+// watched register changes. An empty line repeats the last step or continue.
+
+use crate::{Computer, Program, Register};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[cfg(test)]
+mod test {
+    // Runs the commands against the program, returning everything that the
+    // debugger printed.
+    fn debug(program: &str, commands: &str) -> String {
+        use super::*;
+
+        let mut debugger = Debugger::new(crate::load_program(program).unwrap());
+        let mut output = Vec::new();
+        debugger.run(commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_parse_command() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        assert_eq!(Ok(Command::Step(1)), parse_command("s"));
+        assert_eq!(Ok(Command::Step(10)), parse_command("step 10"));
+        let breakpoint = Breakpoint::When(Register::A, Comparison::Ge, -3);
+        assert_eq!(Ok(Command::Break(breakpoint)), parse_command("break a >= -3"));
+        assert_eq!(Ok(Command::Break(Breakpoint::At(4))), parse_command("b 4"));
+        assert!(parse_command("break e == 1").is_err());
+        assert!(parse_command("frobnicate").is_err());
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        use pretty_assertions::assert_eq;
+
+        let program = "cpy 3 b\ninc a\ndec b\njnz b -2";
+        let output = debug(program, "break 3\ncontinue\nprint\n\nstep 100\nstep\n");
+        let expected = [
+            "(debug) Breakpoint 1: instruction 3",
+            "(debug) Stopped at breakpoint 1 after 3 steps",
+            "a=1 b=2 c=0 d=0",
+            "=> 3: jnz b -2",
+            "(debug) a=1 b=2 c=0 d=0",
+            "=> 3: jnz b -2",
+            // The empty line continued again.
+            "(debug) Stopped at breakpoint 1 after 6 steps",
+            "a=2 b=1 c=0 d=0",
+            "=> 3: jnz b -2",
+            "(debug) Stopped at breakpoint 1 after 9 steps",
+            "a=3 b=0 c=0 d=0",
+            "=> 3: jnz b -2",
+            "(debug) Halted after 10 steps",
+            "a=3 b=0 c=0 d=0",
+            "(halted)",
+            "(debug) ",
+        ];
+        assert_eq!(expected.join("\n"), output);
+
+        let output = debug(program, "set a 10\nbreak a > 11\nc\n");
+        assert!(output.contains("Stopped at breakpoint 1 after 5 steps\na=12 b=2"), "{}", output);
+    }
+
+    #[test]
+    fn test_watch() {
+        use pretty_assertions::assert_eq;
+
+        let output = debug("cpy 2 a\ncpy 2 a\ncpy 5 c\ncpy 1 a", "watch a\nc\nc\nc\nstep\n");
+        let expected = [
+            "(debug) Watching a",
+            "(debug) a changed from 0 to 2 after 1 steps",
+            "a=2 b=0 c=0 d=0",
+            "=> 1: cpy 2 a",
+            // Copying the same value again doesn't count as a change.
+            "(debug) a changed from 2 to 1 after 4 steps",
+            "a=1 b=0 c=5 d=0",
+            "(halted)",
+            "(debug) The program has halted",
+            "(debug) The program has halted",
+            "(debug) ",
+        ];
+        assert_eq!(expected.join("\n"), output);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    const ALL: [(Comparison, &'static str); 6] = [
+        (Comparison::Eq, "=="),
+        (Comparison::Ne, "!="),
+        (Comparison::Lt, "<"),
+        (Comparison::Le, "<="),
+        (Comparison::Gt, ">"),
+        (Comparison::Ge, ">="),
+    ];
+
+    fn from(input: &str) -> Option<Comparison> {
+        Comparison::ALL
+            .iter()
+            .find(|(_, symbol)| *symbol == input)
+            .map(|(comparison, _)| *comparison)
+    }
+
+    fn holds(self, lhs: i32, rhs: i32) -> bool {
+        match self {
+            Comparison::Eq => lhs == rhs,
+            Comparison::Ne => lhs != rhs,
+            Comparison::Lt => lhs < rhs,
+            Comparison::Le => lhs <= rhs,
+            Comparison::Gt => lhs > rhs,
+            Comparison::Ge => lhs >= rhs,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (_, symbol) = Comparison::ALL.iter().find(|(comparison, _)| comparison == self).unwrap();
+        write!(f, "{}", symbol)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Breakpoint {
+    // Stops before running the instruction at this index.
+    At(usize),
+    // Stops as soon as the condition becomes true (rather than after every
+    // step while it stays true).
+    When(Register, Comparison, i32),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::At(idx) => write!(f, "instruction {}", idx),
+            Breakpoint::When(reg, comparison, val) => write!(f, "{} {} {}", reg, comparison, val),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Command {
+    Step(usize),
+    Continue,
+    Print,
+    List,
+    Break(Breakpoint),
+    Watch(Register),
+    // Deletes a single breakpoint, or every breakpoint and watch.
+    Delete(Option<usize>),
+    Set(Register, i32),
+    Help,
+    Quit,
+}
+
+const HELP: &str = "\
+step [n]              run one (or n) instructions
+continue              run until a breakpoint, a watched register changes, or the program halts
+print                 show the registers and the next instruction
+list                  show the whole program
+break <idx>           stop before running instruction idx
+break <reg> <op> <n>  stop when e.g. `a >= 10` becomes true; op is one of == != < <= > >=
+watch <reg>           stop whenever the register changes
+delete [n]            delete breakpoint n, or every breakpoint and watch
+set <reg> <n>         change a register
+quit                  stop debugging";
+
+fn parse_command(line: &str) -> Result<Command, String> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let register = |token: &str| Register::from(token).map_err(|err| format!("{}: {}", token, err.message));
+    let number = |token: &str| token.parse::<i32>().map_err(|err| format!("{}: {}", token, err));
+    let command = match tokens.as_slice() {
+        ["s"] | ["step"] => Command::Step(1),
+        ["s", n] | ["step", n] => Command::Step(n.parse().map_err(|err| format!("{}: {}", n, err))?),
+        ["c"] | ["continue"] => Command::Continue,
+        ["p"] | ["print"] => Command::Print,
+        ["l"] | ["list"] => Command::List,
+        ["b", idx] | ["break", idx] => {
+            Command::Break(Breakpoint::At(idx.parse().map_err(|err| format!("{}: {}", idx, err))?))
+        },
+        ["b", reg, op, val] | ["break", reg, op, val] => {
+            let comparison = Comparison::from(op).ok_or_else(|| format!("{}: expected one of == != < <= > >=", op))?;
+            Command::Break(Breakpoint::When(register(reg)?, comparison, number(val)?))
+        },
+        ["w", reg] | ["watch", reg] => Command::Watch(register(reg)?),
+        ["d"] | ["delete"] => Command::Delete(None),
+        ["d", n] | ["delete", n] => Command::Delete(Some(n.parse().map_err(|err| format!("{}: {}", n, err))?)),
+        ["set", reg, val] => Command::Set(register(reg)?, number(val)?),
+        ["h"] | ["help"] => Command::Help,
+        ["q"] | ["quit"] => Command::Quit,
+        _ => return Err(format!("Unrecognized command {:?}; try help", line.trim())),
+    };
+    Ok(command)
+}
+
+pub struct Debugger {
+    computer: Computer,
+    program: Program,
+    // Index of the next instruction to run, which is outside of the program
+    // once it's finished.
+    idx: i64,
+    num_steps: usize,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<Register>,
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Debugger {
+        Debugger {
+            computer: Computer::new(),
+            program,
+            idx: 0,
+            num_steps: 0,
+            breakpoints: Vec::new(),
+            watches: Vec::new(),
+        }
+    }
+
+    // For setting up the registers before starting, e.g. c = 1 for part 2.
+    pub fn computer_mut(&mut self) -> &mut Computer {
+        &mut self.computer
+    }
+
+    fn halted(&self) -> bool {
+        self.idx < 0 || self.idx >= self.program.len() as i64
+    }
+
+    // Runs a single instruction, returning the reason to stop after it, if
+    // there is one.
+    fn step(&mut self) -> Option<String> {
+        let before = self.computer.registers();
+        self.idx = self.computer.step(&mut self.program, self.idx as usize);
+        self.num_steps += 1;
+        let after = self.computer.registers();
+
+        let mut reasons = Vec::new();
+        for reg in &self.watches {
+            let ii = Register::ALL.iter().position(|other| other == reg).unwrap();
+            if before[ii] != after[ii] {
+                reasons.push(format!("{} changed from {} to {}", reg, before[ii], after[ii]));
+            }
+        }
+        for (num, breakpoint) in self.breakpoints.iter().enumerate() {
+            let hit = match breakpoint {
+                Breakpoint::At(idx) => self.idx == *idx as i64,
+                Breakpoint::When(reg, comparison, val) => {
+                    let ii = Register::ALL.iter().position(|other| other == reg).unwrap();
+                    comparison.holds(after[ii], *val) && !comparison.holds(before[ii], *val)
+                },
+            };
+            if hit {
+                reasons.push(format!("Stopped at breakpoint {}", num + 1));
+            }
+        }
+        if reasons.is_empty() && self.idx == self.program.len() as i64 {
+            reasons.push(String::from("Halted"));
+        } else if reasons.is_empty() && self.halted() {
+            reasons.push(format!("Jumped out of the program to {}", self.idx));
+        }
+        if reasons.is_empty() {
+            None
+        } else {
+            Some(format!("{} after {} steps", reasons.join(", "), self.num_steps))
+        }
+    }
+
+    fn write_state(&self, output: &mut impl Write) -> io::Result<()> {
+        let registers: Vec<String> = Register::ALL
+            .iter()
+            .map(|reg| format!("{}={}", reg, self.computer.get_reg(reg)))
+            .collect();
+        writeln!(output, "{}", registers.join(" "))?;
+        if self.halted() {
+            writeln!(output, "(halted)")
+        } else {
+            writeln!(output, "=> {}: {}", self.idx, self.program[self.idx as usize])
+        }
+    }
+
+    // Runs steps until there's a reason to stop, or `max_steps` have been
+    // run.
+    fn run_steps(&mut self, max_steps: Option<usize>, output: &mut impl Write) -> io::Result<()> {
+        if self.halted() {
+            return writeln!(output, "The program has halted");
+        }
+        let mut num_steps = 0;
+        while !self.halted() && max_steps.is_none_or(|max_steps| num_steps < max_steps) {
+            num_steps += 1;
+            if let Some(reason) = self.step() {
+                writeln!(output, "{}", reason)?;
+                break;
+            }
+        }
+        self.write_state(output)
+    }
+
+    // Runs commands from `input` until it runs out or says to quit, writing
+    // everything to `output`.
+    pub fn run(&mut self, input: impl BufRead, output: &mut impl Write) -> io::Result<()> {
+        let mut lines = input.lines();
+        let mut last_command: Option<Command> = None;
+        loop {
+            write!(output, "(debug) ")?;
+            output.flush()?;
+            let Some(line) = lines.next() else {
+                break;
+            };
+            let line = line?;
+            let command = if line.trim().is_empty() {
+                match &last_command {
+                    Some(command) => command.clone(),
+                    None => continue,
+                }
+            } else {
+                match parse_command(&line) {
+                    Ok(command) => command,
+                    Err(err) => {
+                        writeln!(output, "{}", err)?;
+                        continue;
+                    },
+                }
+            };
+            match &command {
+                Command::Step(n) => self.run_steps(Some(*n), output)?,
+                Command::Continue => self.run_steps(None, output)?,
+                Command::Print => self.write_state(output)?,
+                Command::List => {
+                    for (idx, instruction) in self.program.iter().enumerate() {
+                        let marker = if idx as i64 == self.idx { "=>" } else { "  " };
+                        writeln!(output, "{} {}: {}", marker, idx, instruction)?;
+                    }
+                },
+                Command::Break(breakpoint) => {
+                    self.breakpoints.push(breakpoint.clone());
+                    writeln!(output, "Breakpoint {}: {}", self.breakpoints.len(), breakpoint)?;
+                },
+                Command::Watch(reg) => {
+                    if !self.watches.contains(reg) {
+                        self.watches.push(*reg);
+                    }
+                    writeln!(output, "Watching {}", reg)?;
+                },
+                Command::Delete(None) => {
+                    self.breakpoints.clear();
+                    self.watches.clear();
+                    writeln!(output, "Deleted every breakpoint and watch")?;
+                },
+                Command::Delete(Some(num)) => {
+                    if *num == 0 || *num > self.breakpoints.len() {
+                        writeln!(output, "There's no breakpoint {}", num)?;
+                    } else {
+                        // Later breakpoints get renumbered.
+                        let breakpoint = self.breakpoints.remove(num - 1);
+                        writeln!(output, "Deleted breakpoint {}: {}", num, breakpoint)?;
+                    }
+                },
+                Command::Set(reg, val) => self.computer.set_reg(reg, *val),
+                Command::Help => writeln!(output, "{}", HELP)?,
+                Command::Quit => break,
+            }
+            if matches!(command, Command::Step(_) | Command::Continue) {
+                last_command = Some(command);
+            }
+        }
+        Ok(())
+    }
+}
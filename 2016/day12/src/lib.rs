@@ -0,0 +1,629 @@
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::fmt;
+
+pub mod debugger;
+pub mod profiler;
+
+use profiler::Profiler;
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_parse_error() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        let err = load_program("cpy 41 a\ninc e").unwrap_err();
+        assert_eq!((2, 5), (err.line, err.column));
+        assert_eq!("e", err.text);
+
+        let err = load_program("cpy 41 a\ninc a\njnz a two").unwrap_err();
+        assert_eq!((3, 7), (err.line, err.column));
+
+        let err = load_program("mul a b").unwrap_err();
+        assert_eq!((1, 1), (err.line, err.column));
+        assert_eq!("mul", err.text);
+
+        // The destination still has to be a register, even though toggling
+        // can turn a jnz into a cpy that doesn't have one.
+        let err = load_program("cpy 1 2").unwrap_err();
+        assert_eq!((1, 7), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_toggle() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        let mut program = load_program("cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a").unwrap();
+        let mut computer = Computer::new();
+        computer.run_program(&mut program);
+        assert_eq!(3, computer.get_reg(&Register::A));
+        let toggled = load_program("cpy 2 a\ntgl a\ntgl a\ninc a\njnz 1 a\ndec a\ndec a").unwrap();
+        assert_eq!(toggled, program);
+
+        // Toggling the jnz makes it `cpy 1 2`, which is skipped.
+        let mut program = load_program("tgl 1\njnz 1 2\ninc a").unwrap();
+        let mut computer = Computer::new();
+        computer.run_program(&mut program);
+        assert_eq!(1, computer.get_reg(&Register::A));
+    }
+
+    // Runs the program both as it is and optimized, returning the registers
+    // that each left behind.
+    fn run_both(input: &str, c: i32) -> (Vec<i32>, Vec<i32>) {
+        use super::*;
+
+        let registers = |program: &mut Program| {
+            let mut computer = Computer::new();
+            computer.set_reg(&Register::C, c);
+            computer.run_program(program);
+            [Register::A, Register::B, Register::C, Register::D]
+                .iter()
+                .map(|reg| computer.get_reg(reg))
+                .collect()
+        };
+        let program = load_program(input).unwrap();
+        (registers(&mut program.clone()), registers(&mut optimize(&program)))
+    }
+
+    #[test]
+    fn test_optimize() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        let program = load_program("cpy 3 b\ninc a\ndec b\njnz b -2\ncpy 4 c\ndec c\njnz c -1").unwrap();
+        let optimized = optimize(&program);
+        assert_eq!(Instruction::Add(Register::A, Register::B), optimized[1]);
+        assert_eq!(Instruction::Zero(Register::C), optimized[5]);
+        // Everything after the first instruction of each loop is left alone,
+        // so that jumps into the middle of them still work.
+        assert_eq!(&program[2..5], &optimized[2..5]);
+
+        let program = load_program("cpy 16 c\ncpy 17 d\ndec d\ninc a\njnz d -2\ndec c\njnz c -5").unwrap();
+        let optimized = optimize(&program);
+        let mul = Instruction::Mul(Operand::Number(17), Register::A, Register::D, Register::C);
+        assert_eq!(mul, optimized[1]);
+        assert_eq!(Instruction::Add(Register::A, Register::D), optimized[2]);
+
+        // Toggling could change the loops, so programs with tgl are left
+        // alone.
+        let program = load_program("tgl a\ninc a\ndec b\njnz b -2").unwrap();
+        assert_eq!(program, optimize(&program));
+    }
+
+    #[test]
+    fn test_optimize_results() {
+        use pretty_assertions::assert_eq;
+
+        // Including a jump into the middle of a loop that's been optimized.
+        let (expected, actual) = run_both("cpy 3 b\njnz 1 2\ninc a\ndec b\njnz b -2\ncpy 2 d\ndec d\njnz d -1", 0);
+        assert_eq!(vec![2, 0, 0, 0], expected);
+        assert_eq!(expected, actual);
+
+        let input = std::fs::read_to_string("input.txt").unwrap();
+        for c in 0..=1 {
+            let (expected, actual) = run_both(&input, c);
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_run_result() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        let run = |input: &str| {
+            let mut computer = Computer::new();
+            computer.set_step_limit(Some(1000));
+            computer.run_program(&mut load_program(input).unwrap())
+        };
+        assert_eq!(RunResult::Halted, run("cpy 3 a\njnz a 2\ninc a"));
+        // Jumping before the start doesn't wrap around to a huge index.
+        assert_eq!(RunResult::JumpedOutOfBounds { from: 1, to: -4 }, run("inc a\njnz a -5"));
+        assert_eq!(RunResult::JumpedOutOfBounds { from: 0, to: 3 }, run("jnz 1 3\ninc a"));
+        assert_eq!(RunResult::Looped { period: 1 }, run("inc a\njnz 1 0"));
+        assert_eq!(RunResult::Looped { period: 2 }, run("cpy 1 a\ncpy 2 a\njnz 1 -1"));
+        // Counting forever never gets back to the same state.
+        assert_eq!(RunResult::StepLimitExceeded, run("inc a\njnz 1 -1"));
+        // Neither does a program that keeps toggling itself (as far as the
+        // computer knows).
+        assert_eq!(RunResult::StepLimitExceeded, run("cpy 2 a\ntgl a\njnz 1 -1\ninc a"));
+
+        let mut computer = Computer::new();
+        let mut program = load_program("out a\njnz 1 -1").unwrap();
+        assert_eq!(RunResult::OutputLimitReached, computer.run_for_outputs(&mut program, 1));
+    }
+
+    #[test]
+    fn test_out() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        let mut program = load_program("cpy 3 b\nout b\ndec b\njnz b -2\nout a").unwrap();
+        let mut computer = Computer::new();
+        computer.set_reg(&Register::A, 7);
+        computer.run_program(&mut program);
+        assert_eq!(&[3, 2, 1, 7], computer.outputs());
+
+        // Programs that never stop can be cut off after enough outputs.
+        let mut program = load_program("out a\ninc a\njnz 1 -2").unwrap();
+        let mut computer = Computer::new();
+        computer.run_for_outputs(&mut program, 4);
+        assert_eq!(&[0, 1, 2, 3], computer.outputs());
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+// The assembunny VM: four registers, all starting at 0, and everything that
+// the program has sent with `out`.
+pub struct Computer {
+    // Indexed by Register.
+    registers: [i32; 4],
+    outputs: Vec<i32>,
+    profiler: Option<Profiler>,
+    step_limit: Option<usize>,
+    // Number of instructions that `tgl` has changed, since the program isn't
+    // in the same state once one has been.
+    num_toggles: usize,
+}
+
+// Why the computer stopped running a program.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RunResult {
+    // Ran off the end of the program (or jumped to just past it).
+    Halted,
+    // Jumped anywhere else outside of the program.
+    JumpedOutOfBounds { from: usize, to: i64 },
+    // Was still running after the step limit.
+    StepLimitExceeded,
+    // Got back to exactly the same state as before, so it would have kept
+    // repeating the last `period` steps forever.
+    Looped { period: usize },
+    // Sent as many outputs as run_for_outputs asked for.
+    OutputLimitReached,
+}
+
+// Everything that decides what the computer does next.
+type MachineState = (usize, [i32; 4], usize);
+
+impl Default for Computer {
+    fn default() -> Computer {
+        Computer::new()
+    }
+}
+
+impl Computer {
+    pub fn new() -> Computer {
+        Computer {
+            registers: [0; 4],
+            outputs: Vec::new(),
+            profiler: None,
+            step_limit: None,
+            num_toggles: 0,
+        }
+    }
+
+    pub fn get_reg(&self, reg: &Register) -> i32 {
+        self.registers[*reg as usize]
+    }
+
+    pub fn set_reg(&mut self, reg: &Register, val: i32) {
+        self.registers[*reg as usize] = val;
+    }
+
+    // Every register's value, in the order of Register::ALL.
+    pub fn registers(&self) -> [i32; 4] {
+        self.registers
+    }
+
+    pub fn value(&self, operand: &Operand) -> i32 {
+        match operand {
+            Operand::Register(reg) => self.get_reg(reg),
+            Operand::Number(ii) => *ii,
+        }
+    }
+
+    // Everything that the program has sent with `out`, oldest first.
+    pub fn outputs(&self) -> &[i32] {
+        &self.outputs
+    }
+
+    // Profiles every instruction that's run from now on.
+    pub fn set_profiler(&mut self, profiler: Profiler) {
+        self.profiler = Some(profiler);
+    }
+
+    pub fn take_profiler(&mut self) -> Option<Profiler> {
+        self.profiler.take()
+    }
+
+    // Gives up on programs after this many steps. There's no limit by
+    // default.
+    pub fn set_step_limit(&mut self, step_limit: Option<usize>) {
+        self.step_limit = step_limit;
+    }
+
+    // Runs the program until it jumps outside of it, or can be shown never
+    // to. `tgl` changes the program itself, so it has to be mutable (and is
+    // left toggled).
+    pub fn run_program(&mut self, instructions: &mut Program) -> RunResult {
+        self.run(instructions, None)
+    }
+
+    // Like run_program, but also stops once the program has sent
+    // `num_outputs` values, for programs that are meant to run forever. A
+    // program that's found to be looping stops as soon as it's found, since
+    // any more outputs would just repeat what it sent in its last `period`
+    // steps.
+    pub fn run_for_outputs(&mut self, instructions: &mut Program, num_outputs: usize) -> RunResult {
+        self.run(instructions, Some(num_outputs))
+    }
+
+    fn state(&self, idx: usize) -> MachineState {
+        (idx, self.registers(), self.num_toggles)
+    }
+
+    fn run(&mut self, instructions: &mut Program, max_outputs: Option<usize>) -> RunResult {
+        let mut idx = 0;
+        let mut num_steps = 0;
+        // Rather than remembering every state, which would take far too
+        // much memory, loops are found with Brent's algorithm: each state is
+        // compared with a single saved one, which is replaced whenever the
+        // number of steps reaches a power of two. Once the program is in a
+        // loop, it'll get back to the saved state within a couple of times
+        // as many steps as it took to get there.
+        let mut saved = (self.state(idx), 0);
+        loop {
+            if idx == instructions.len() {
+                return RunResult::Halted;
+            }
+            if max_outputs.is_some_and(|max_outputs| self.outputs.len() >= max_outputs) {
+                return RunResult::OutputLimitReached;
+            }
+            if self.step_limit.is_some_and(|step_limit| num_steps >= step_limit) {
+                return RunResult::StepLimitExceeded;
+            }
+            let next_idx = if self.profiler.is_some() {
+                self.profiled_step(instructions, idx)
+            } else {
+                self.step(instructions, idx)
+            };
+            num_steps += 1;
+            if next_idx < 0 || next_idx > instructions.len() as i64 {
+                return RunResult::JumpedOutOfBounds { from: idx, to: next_idx };
+            }
+            idx = next_idx as usize;
+
+            let state = self.state(idx);
+            let (saved_state, saved_step) = &saved;
+            if state == *saved_state {
+                return RunResult::Looped { period: num_steps - saved_step };
+            }
+            if num_steps.is_power_of_two() {
+                saved = (state, num_steps);
+            }
+        }
+    }
+
+    fn profiled_step(&mut self, instructions: &mut Program, idx: usize) -> i64 {
+        // The profiler is taken out while the step runs, since that needs
+        // the rest of the computer.
+        let mut profiler = self.profiler.take().unwrap();
+        profiler.record(idx, &instructions[idx], &self.registers());
+        let next_idx = self.step(instructions, idx);
+        profiler.record_jump(idx, next_idx);
+        self.profiler = Some(profiler);
+        next_idx
+    }
+
+    // Runs the instruction at `idx`, returning the index of the next one,
+    // which might be outside of the program.
+    pub fn step(&mut self, instructions: &mut Program, idx: usize) -> i64 {
+        let mut idx = idx as i64;
+        // Toggling can produce instructions that don't make sense, like
+        // `inc 1`; those are skipped.
+        match &instructions[idx as usize] {
+            Instruction::Cpy(val, Operand::Register(dest_reg)) => {
+                self.set_reg(dest_reg, self.value(val));
+                idx += 1;
+            },
+            Instruction::Inc(Operand::Register(reg)) => {
+                self.registers[*reg as usize] += 1;
+                idx += 1;
+            },
+            Instruction::Dec(Operand::Register(reg)) => {
+                self.registers[*reg as usize] -= 1;
+                idx += 1;
+            },
+            Instruction::Jnz(val, jmp) => {
+                if 0 != self.value(val) {
+                    idx += i64::from(self.value(jmp));
+                } else {
+                    idx += 1;
+                }
+            },
+            Instruction::Tgl(offset) => {
+                let target = idx + i64::from(self.value(offset));
+                if target >= 0 && target < instructions.len() as i64 {
+                    let target = target as usize;
+                    instructions[target] = instructions[target].toggled();
+                    self.num_toggles += 1;
+                }
+                idx += 1;
+            },
+            Instruction::Out(val) => {
+                self.outputs.push(self.value(val));
+                idx += 1;
+            },
+            Instruction::Add(dest_reg, src_reg) => {
+                self.set_reg(dest_reg, self.get_reg(dest_reg) + self.get_reg(src_reg));
+                self.set_reg(src_reg, 0);
+                idx += 3;
+            },
+            Instruction::Mul(val, dest_reg, inner_reg, outer_reg) => {
+                let product = self.value(val) * self.get_reg(outer_reg);
+                self.set_reg(dest_reg, self.get_reg(dest_reg) + product);
+                self.set_reg(inner_reg, 0);
+                self.set_reg(outer_reg, 0);
+                idx += 6;
+            },
+            Instruction::Zero(reg) => {
+                self.set_reg(reg, 0);
+                idx += 2;
+            },
+            Instruction::Cpy(_, Operand::Number(_))
+            | Instruction::Inc(Operand::Number(_))
+            | Instruction::Dec(Operand::Number(_)) => {
+                idx += 1;
+            },
+        }
+        idx
+    }
+}
+
+// Operands that have to be registers in the source are still stored as
+// Operands, since `tgl` can turn an instruction into one where they aren't.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Instruction {
+  Cpy(Operand, Operand),
+  Inc(Operand),
+  Dec(Operand),
+  Jnz(Operand, Operand),
+  Tgl(Operand),
+  Out(Operand),
+  // These never appear in the source, and are only produced by `optimize`.
+  // Each one stands in for a whole loop, and skips over the rest of it.
+  Add(Register, Register),
+  Mul(Operand, Register, Register, Register),
+  Zero(Register),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Operand {
+    Register(Register),
+    Number(i32),
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Register::A, Register::B, Register::C, Register::D];
+
+    pub fn from(input: &str) -> Result<Register, ParseError> {
+        match input {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            "c" => Ok(Register::C),
+            "d" => Ok(Register::D),
+            _ => Err(ParseError::new(input, input, "expected a register (a-d)")),
+        }
+    }
+}
+
+impl Operand {
+    pub fn from(input: &str) -> Result<Operand, ParseError> {
+        let parsed = input.parse::<i32>();
+        if let Ok(num) = parsed {
+            Ok(Operand::Number(num))
+        } else {
+            match Register::from(input) {
+                Ok(reg) => Ok(Operand::Register(reg)),
+                Err(_) => Err(ParseError::new(input, input, "expected a register (a-d) or a number")),
+            }
+        } 
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Register::A => "a",
+            Register::B => "b",
+            Register::C => "c",
+            Register::D => "d",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Register(reg) => write!(f, "{}", reg),
+            Operand::Number(ii) => write!(f, "{}", ii),
+        }
+    }
+}
+
+// Writes instructions the way they appear in the source. The optimized ones
+// don't have a source, so they get names of their own.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Cpy(x, y) => write!(f, "cpy {} {}", x, y),
+            Instruction::Inc(x) => write!(f, "inc {}", x),
+            Instruction::Dec(x) => write!(f, "dec {}", x),
+            Instruction::Jnz(x, y) => write!(f, "jnz {} {}", x, y),
+            Instruction::Tgl(x) => write!(f, "tgl {}", x),
+            Instruction::Out(x) => write!(f, "out {}", x),
+            Instruction::Add(dest, src) => write!(f, "add {} {}", dest, src),
+            Instruction::Mul(val, dest, inner, outer) => write!(f, "mul {} {} {} {}", val, dest, inner, outer),
+            Instruction::Zero(reg) => write!(f, "zero {}", reg),
+        }
+    }
+}
+
+impl Instruction {
+    /*
+    cpy x y copies x (either an integer or the value of a register) into register y.
+    inc x increases the value of register x by one.
+    dec x decreases the value of register x by one.
+    jnz x y jumps to an instruction y away (positive means forward; negative means backward), but only if x is not zero.
+    tgl x toggles the instruction x away (pointing at instructions like jnz does: positive means forward; negative means backward).
+    out x transmits x (either an integer or the value of a register) as the next value for the clock signal.
+    */
+    pub fn from(input: &str) -> Result<Instruction, ParseError> {
+        let tokens: Vec<&str> = input.split_whitespace().collect();
+        let num_args = match tokens.first() {
+            Some(&"cpy") | Some(&"jnz") => 2,
+            Some(&"inc") | Some(&"dec") | Some(&"tgl") | Some(&"out") => 1,
+            Some(op) => return Err(ParseError::new(input, op, "unrecognized instruction")),
+            None => return Err(ParseError::new(input, input, "missing instruction")),
+        };
+        if tokens.len() != num_args + 1 {
+            let msg = format!("expected {} arguments to {}", num_args, tokens[0]);
+            return Err(ParseError::new(input, input, msg));
+        }
+        // Errors from parsing a single token need their column relative to
+        // the whole line.
+        let register = |token: &str| {
+            Register::from(token)
+                .map(Operand::Register)
+                .map_err(|err| ParseError::new(input, token, err.message))
+        };
+        let operand = |token: &str| {
+            Operand::from(token).map_err(|err| ParseError::new(input, token, err.message))
+        };
+        match tokens[0] {
+            "cpy" => {
+                Ok(Instruction::Cpy(operand(tokens[1])?, register(tokens[2])?))
+            },
+            "inc" => {
+                Ok(Instruction::Inc(register(tokens[1])?))
+            },
+            "dec" => {
+                Ok(Instruction::Dec(register(tokens[1])?))
+            },
+            "tgl" => {
+                Ok(Instruction::Tgl(operand(tokens[1])?))
+            },
+            "out" => {
+                Ok(Instruction::Out(operand(tokens[1])?))
+            },
+            _ => {
+                Ok(Instruction::Jnz(operand(tokens[1])?, operand(tokens[2])?))
+            },
+        }
+    }
+
+    // What `tgl` turns this instruction into: inc becomes dec and every other
+    // one-argument instruction becomes inc, while jnz becomes cpy and every
+    // other two-argument instruction becomes jnz.
+    pub fn toggled(&self) -> Instruction {
+        match self {
+            Instruction::Inc(x) => Instruction::Dec(x.clone()),
+            Instruction::Dec(x) | Instruction::Tgl(x) | Instruction::Out(x) => Instruction::Inc(x.clone()),
+            Instruction::Jnz(x, y) => Instruction::Cpy(x.clone(), y.clone()),
+            Instruction::Cpy(x, y) => Instruction::Jnz(x.clone(), y.clone()),
+            // `optimize` doesn't touch programs that could toggle anything.
+            Instruction::Add(..) | Instruction::Mul(..) | Instruction::Zero(..) => self.clone(),
+        }
+    }
+}
+
+pub type Program = Vec<Instruction>;
+pub fn load_program(input: &str) -> Result<Program, ParseError> {
+    parse_lines(input, Instruction::from)
+}
+
+// If the instructions starting at `program[0]` are one of the counting loops
+// that `optimize` knows about, the instruction that does the same thing.
+fn optimized_loop(program: &[Instruction]) -> Option<Instruction> {
+    use Instruction::*;
+    use Operand::{Number, Register as Reg};
+    match program {
+        // dest += src, one at a time.
+        [Inc(Reg(dest)), Dec(Reg(src)), Jnz(Reg(counter), Number(-2)), ..]
+        | [Dec(Reg(src)), Inc(Reg(dest)), Jnz(Reg(counter), Number(-2)), ..]
+            if src == counter && dest != src =>
+        {
+            Some(Add(*dest, *src))
+        },
+        // The same, but adding val to dest outer times.
+        [Cpy(val, Reg(inner)), rest @ ..] => {
+            let Some(Add(dest, src)) = optimized_loop(rest) else {
+                return None;
+            };
+            let [_, _, _, Dec(Reg(outer)), Jnz(Reg(counter), Number(-5)), ..] = rest else {
+                return None;
+            };
+            let registers_match = src == *inner && outer == counter && dest != *outer && inner != outer;
+            let val_is_constant = ![dest, *inner, *outer].iter().any(|reg| *val == Reg(*reg));
+            if registers_match && val_is_constant {
+                Some(Mul(val.clone(), dest, *inner, *outer))
+            } else {
+                None
+            }
+        },
+        [Dec(Reg(reg)), Jnz(Reg(counter), Number(-1)), ..] if reg == counter => Some(Zero(*reg)),
+        _ => None,
+    }
+}
+
+// Speeds up the program by replacing the first instruction of each counting
+// loop with one that does the whole loop at once. The loops' other
+// instructions are left where they are, so that jump offsets (and jumps into
+// the middle of a loop) still work. Like the loops, the replacements assume
+// that their counters start out positive.
+//
+// A program that uses tgl could change its loops as it runs, so it's left
+// alone.
+pub fn optimize(program: &Program) -> Program {
+    if program.iter().any(|instruction| matches!(instruction, Instruction::Tgl(_))) {
+        return program.clone();
+    }
+    (0..program.len())
+        .map(|idx| optimized_loop(&program[idx..]).unwrap_or_else(|| program[idx].clone()))
+        .collect()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Program;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_program(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> i32 {
+        let mut computer = Computer::new();
+        computer.run_program(&mut optimize(instructions));
+        computer.get_reg(&Register::A)
+    }
+
+    fn part2(instructions: &Self::Input) -> i32 {
+        let mut computer = Computer::new();
+        computer.set_reg(&Register::C, 1);
+        computer.run_program(&mut optimize(instructions));
+        computer.get_reg(&Register::A)
+    }
+}
@@ -0,0 +1,219 @@
+// Where an assembunny program spends its time: how many times each
+// instruction ran, and which loops ran the most steps. Hand one to
+// `Computer::set_profiler` before running the program, and optionally have
+// it write a trace of every step to a file.
+//
+// A loop is any backward jump, running from the instruction that was
+// jumped to through the jump itself. Loops that are inside other loops are
+// counted in both.
+
+use crate::{Instruction, Program, Register};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+#[cfg(test)]
+mod test {
+    // Adds 2 to a, three times over.
+    const PROGRAM: &str = "cpy 3 b\ncpy 2 c\ninc a\ndec c\njnz c -2\ndec b\njnz b -5";
+
+    fn profile(profiler: super::Profiler) -> super::Profiler {
+        use crate::{Computer, Register};
+
+        let mut program = crate::load_program(PROGRAM).unwrap();
+        let mut computer = Computer::new();
+        computer.set_profiler(profiler);
+        computer.run_program(&mut program);
+        assert_eq!(6, computer.get_reg(&Register::A));
+        computer.take_profiler().unwrap()
+    }
+
+    #[test]
+    fn test_counts() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        let profiler = profile(Profiler::new());
+        assert_eq!(28, profiler.num_steps());
+        assert_eq!(&[1, 3, 6, 6, 6, 3, 3], profiler.counts());
+        let loops = profiler.hottest_loops();
+        assert_eq!(
+            vec![
+                LoopProfile { start: 1, end: 6, iterations: 2, num_steps: 27 },
+                LoopProfile { start: 2, end: 4, iterations: 3, num_steps: 18 },
+            ],
+            loops
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        let program = crate::load_program(PROGRAM).unwrap();
+        let expected = "\
+28 steps
+
+Hottest instructions:
+       6  2: inc a
+       6  3: dec c
+       6  4: jnz c -2
+       3  1: cpy 2 c
+       3  5: dec b
+
+Hottest loops:
+  1-6: 27 steps, 2 iterations
+  2-4: 18 steps, 3 iterations
+";
+        assert_eq!(expected, profile(Profiler::new()).report(&program, 5));
+    }
+
+    #[test]
+    fn test_trace() {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        let path = std::env::temp_dir().join(format!("assembunny-trace-{}.txt", std::process::id()));
+        let mut profiler = profile(Profiler::with_trace(&path).unwrap());
+        profiler.finish().unwrap();
+        let trace = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let lines: Vec<&str> = trace.lines().collect();
+        assert_eq!(28, lines.len());
+        // Each line has the registers from before the instruction ran.
+        assert_eq!("0 cpy 3 b a=0 b=0 c=0 d=0", lines[0]);
+        assert_eq!("4 jnz c -2 a=2 b=3 c=0 d=0", lines[7]);
+        assert_eq!("6 jnz b -5 a=6 b=0 c=0 d=0", lines[27]);
+    }
+}
+
+// How much time the program spent in one loop.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LoopProfile {
+    // The instruction that the loop jumps back to.
+    pub start: usize,
+    // The jump back.
+    pub end: usize,
+    // Number of times that the jump back was taken.
+    pub iterations: usize,
+    // Number of steps spent on the loop's instructions (including any time
+    // they spent running outside of the loop).
+    pub num_steps: usize,
+}
+
+#[derive(Default)]
+pub struct Profiler {
+    // Indexed by instruction; only as long as the last instruction run.
+    counts: Vec<usize>,
+    num_steps: usize,
+    // How often each backward jump was taken, by (from, to).
+    back_jumps: BTreeMap<(usize, usize), usize>,
+    trace: Option<BufWriter<File>>,
+    // The first error writing the trace, which stops any more being written.
+    trace_error: Option<io::Error>,
+}
+
+impl Profiler {
+    pub fn new() -> Profiler {
+        Profiler::default()
+    }
+
+    // Also writes a line to `path` for each step, with the instruction's
+    // index, the instruction and the registers before it ran.
+    pub fn with_trace(path: &Path) -> io::Result<Profiler> {
+        let file = File::create(path)?;
+        Ok(Profiler {
+            trace: Some(BufWriter::new(file)),
+            ..Profiler::default()
+        })
+    }
+
+    // Called by the computer just before it runs an instruction.
+    pub fn record(&mut self, idx: usize, instruction: &Instruction, registers: &[i32; 4]) {
+        if self.counts.len() <= idx {
+            self.counts.resize(idx + 1, 0);
+        }
+        self.counts[idx] += 1;
+        self.num_steps += 1;
+
+        if let Some(trace) = &mut self.trace {
+            let registers: Vec<String> = Register::ALL
+                .iter()
+                .zip(registers)
+                .map(|(reg, val)| format!("{}={}", reg, val))
+                .collect();
+            if let Err(err) = writeln!(trace, "{} {} {}", idx, instruction, registers.join(" ")) {
+                self.trace = None;
+                self.trace_error = Some(err);
+            }
+        }
+    }
+
+    // Called by the computer just after it runs an instruction, with the
+    // index of the next one (which might be outside of the program).
+    pub fn record_jump(&mut self, idx: usize, next_idx: i64) {
+        if (0..idx as i64).contains(&next_idx) {
+            *self.back_jumps.entry((idx, next_idx as usize)).or_insert(0) += 1;
+        }
+    }
+
+    // Finishes writing the trace, returning the first error there was in
+    // writing it.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(err) = self.trace_error.take() {
+            return Err(err);
+        }
+        match &mut self.trace {
+            Some(trace) => trace.flush(),
+            None => Ok(()),
+        }
+    }
+
+    pub fn num_steps(&self) -> usize {
+        self.num_steps
+    }
+
+    // Number of times that each instruction ran.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    // Every loop that ran, the one that took the most steps first.
+    pub fn hottest_loops(&self) -> Vec<LoopProfile> {
+        let mut loops: Vec<LoopProfile> = self
+            .back_jumps
+            .iter()
+            .map(|(&(end, start), &iterations)| LoopProfile {
+                start,
+                end,
+                iterations,
+                num_steps: self.counts[start..=end].iter().sum(),
+            })
+            .collect();
+        // Stable, so ties stay in the order of the loops' positions.
+        loops.sort_by_key(|profile| std::cmp::Reverse(profile.num_steps));
+        loops
+    }
+
+    // A summary of the `num_hottest` instructions that ran the most, and
+    // the `num_hottest` loops that took the most steps.
+    pub fn report(&self, program: &Program, num_hottest: usize) -> String {
+        let mut report = format!("{} steps\n\nHottest instructions:\n", self.num_steps);
+        let mut hottest: Vec<(usize, usize)> = self.counts.iter().copied().enumerate().collect();
+        hottest.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        for (idx, count) in hottest.into_iter().take(num_hottest).filter(|(_, count)| *count > 0) {
+            report.push_str(&format!("{:>8}  {}: {}\n", count, idx, program[idx]));
+        }
+        report.push_str("\nHottest loops:\n");
+        for profile in self.hottest_loops().into_iter().take(num_hottest) {
+            report.push_str(&format!(
+                "  {}-{}: {} steps, {} iterations\n",
+                profile.start, profile.end, profile.num_steps, profile.iterations
+            ));
+        }
+        report
+    }
+}
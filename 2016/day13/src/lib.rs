@@ -0,0 +1,186 @@
+use aoc_common::{search, visualize, Params, ParseError, Point, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_is_wall() {
+        // The example inputs have 10 as the designer's favorite number
+        let num = 10;
+        assert!(!is_wall(&Point::new(0, 0), num));
+        assert!(!is_wall(&Point::new(2, 0), num));
+        assert!(!is_wall(&Point::new(7, 0), num));
+        assert!(!is_wall(&Point::new(1, 1), num));
+        assert!(!is_wall(&Point::new(3, 1), num));
+        assert!(!is_wall(&Point::new(3, 2), num));
+        assert!(!is_wall(&Point::new(9, 2), num));
+        assert!(!is_wall(&Point::new(3, 3), num));
+        assert!(!is_wall(&Point::new(5, 3), num));
+        assert!(!is_wall(&Point::new(0, 4), num));
+        assert!(!is_wall(&Point::new(6, 5), num));
+
+        assert!(is_wall(&Point::new(1, 0), num));
+        assert!(is_wall(&Point::new(3, 0), num));
+        assert!(is_wall(&Point::new(2, 1), num));
+        assert!(is_wall(&Point::new(5, 1), num));
+        assert!(is_wall(&Point::new(0, 2), num));
+        assert!(is_wall(&Point::new(5, 2), num));
+    }
+
+    #[test]
+    fn test_get_neighbors() {
+        let set1 = get_neighbors(&Point::new(0, 0));
+        assert_eq!(2, set1.len());
+        let set2 = get_neighbors(&Point::new(1, 1));
+        assert_eq!(4, set2.len());
+        let set3 = get_neighbors(&Point::new(0, 1));
+        assert_eq!(3, set3.len());
+    }
+
+    #[test]
+    fn test_part1() {
+        let num = 10;
+        let start_point = Point::new(1, 1);
+        let end_point = Point::new(7, 4);
+        let path_length = shortest_path(num, start_point, end_point);
+        assert_eq!(path_length, 11)
+    }
+
+    #[test]
+    fn test_parse() {
+        let office = Day13::parse("favorite_number = 10\ntarget = 7,4").unwrap();
+        assert_eq!(10, office.favorite_number);
+        assert_eq!(Point::new(7, 4), office.target);
+
+        let err = Day13::parse("favorite_number = 10\ntarget = 7,-4").unwrap_err();
+        assert_eq!((2, 10), (err.line, err.column));
+        assert_eq!("7,-4", err.text);
+    }
+}
+
+/**
+ * In order to determine whether a cell is a wall:
+ * - Find x*x + 3*x + 2*x*y + y + y*y.
+ * - Add the office designer's favorite number (your puzzle input).
+ * - Find the binary representation of that sum; count the number of bits that are 1.
+ *   - If the number of bits that are 1 is even, it's an open space.
+ *   - If the number of bits that are 1 is odd, it's a wall.
+ */
+pub fn is_wall(pt: &Point, offset: u32) -> bool {
+    // The office does not extend into negative coordinates
+    assert!(pt.x >= 0 && pt.y >= 0);
+    let (x, y) = (pt.x as u32, pt.y as u32);
+    let val = x * x + 3 * x + 2 * x * y + y + y * y + offset;
+    let num_ones = val.count_ones();
+    num_ones % 2 == 1
+}
+
+pub fn get_neighbors(pt: &Point) -> HashSet<Point> {
+    // There is no upper bound to valid coordinates, but the office does
+    // not extend into negative coordinates
+    let min = Point::ORIGIN;
+    let max = Point::new(i32::MAX, i32::MAX);
+    pt.neighbors4()
+        .iter()
+        .filter(|neighbor| neighbor.in_bounds(min, max))
+        .cloned()
+        .collect()
+}
+
+// The neighbors that aren't walls, i.e. the places we can actually go.
+fn open_neighbors(pt: &Point, offset: u32) -> Vec<Point> {
+    log::trace!("Exploring pt: {}, {}", pt.x, pt.y);
+    get_neighbors(pt)
+        .into_iter()
+        .filter(|neighbor| !is_wall(neighbor, offset))
+        .collect()
+}
+
+// Every step costs the same, so a breadth-first search finds the shortest path.
+pub fn shortest_path(offset: u32, start: Point, end: Point) -> usize {
+    // The office is infinite, so the search only stops if it finds the end.
+    search::bfs(start, |pt| open_neighbors(pt, offset), |pt| *pt == end)
+        .goal_distance()
+        .expect("Have run out of states to explore but haven't found goal")
+}
+
+// Draws the part of the office that the flood has reached, with walls as '#'
+// and everywhere reachable within `steps` as 'O'.
+fn render_flood(offset: u32, distances: &HashMap<Point, usize>, steps: usize) -> String {
+    let max_x = distances.keys().map(|pt| pt.x).max().unwrap_or(0) + 1;
+    let max_y = distances.keys().map(|pt| pt.y).max().unwrap_or(0) + 1;
+    let mut lines = vec![format!("Step {}", steps)];
+    for y in 0..=max_y {
+        let line: String = (0..=max_x)
+            .map(|x| {
+                let pt = Point::new(x, y);
+                if is_wall(&pt, offset) {
+                    '#'
+                } else if distances.get(&pt).is_some_and(|dist| *dist <= steps) {
+                    'O'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+// Determine how many cells are reachable from the start in a given number of steps
+pub fn flood_fill(offset: u32, start: Point, steps: usize) -> usize {
+    let result = search::explore(start, |pt| open_neighbors(pt, offset), steps);
+    // Animate the flood one step at a time, now that we know how far away
+    // everything is.
+    for step in 0..=steps {
+        visualize::frame(|| render_flood(offset, result.distances(), step));
+    }
+    result.distances().len()
+}
+
+// Everything needed to describe the puzzle: the favorite number determines
+// the layout, and part 1 asks how to get to the target.
+#[derive(Debug)]
+pub struct Office {
+    pub favorite_number: u32,
+    pub target: Point,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Office;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let params = Params::parse(input)?;
+        let favorite_number = params.get("favorite_number")?;
+        let target: Point = params.get("target")?;
+        if target.x < 0 || target.y < 0 {
+            return Err(params.error("target", "must not be negative"));
+        }
+        Ok(Office { favorite_number, target })
+    }
+
+    /**
+     * Part 1 asks for the minimum number of steps required to reach a given point.
+     */
+    fn part1(office: &Self::Input) -> usize {
+        let start_point = Point::new(1, 1);
+        shortest_path(office.favorite_number, start_point, office.target)
+    }
+
+    /**
+     * Part 2 asks for how many locations can be reached in a given number of steps.
+     */
+    fn part2(office: &Self::Input) -> usize {
+        let start_point = Point::new(1, 1);
+        let num_steps = 50;
+        flood_fill(office.favorite_number, start_point, num_steps)
+    }
+}
@@ -0,0 +1,176 @@
+use aoc_common::input::single_line;
+use aoc_common::{ParseError, Solution};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  // Making sure that I'm using the various md5 functions correctly, 
+  // using examples from the problem statement.
+  fn test_is_key() {
+    let salt = "abc";
+    let index1 = 18;
+    assert_eq!(Some('8'), find_triple(salt, index1));
+    assert!(!is_key(salt, index1));
+
+    let index2 = 39;
+    assert_eq!(Some('e'), find_triple(salt, index2));
+    assert!(is_key(salt, index2));
+
+    let index3 = 92;
+    assert!(is_key(salt, index3));
+
+    let index4 = 22728;
+    assert!(is_key(salt, index4));
+  }
+
+  #[test]
+  fn test_extended_hash() {
+      let salt = "abc";
+      let index = 0;
+      let hash = extended_hash(salt, index);
+      assert_eq!(hash, "a107ff634856bb300138cac6568c0f24");
+  }
+
+  #[test]
+  fn test_extended_triple() {
+      let salt = "abc";
+      let index1 = 5;
+      let hash1 = extended_hash(salt, index1);
+      let digit1 = extended_triple(&hash1);
+      assert_eq!(Some('2'), digit1);
+
+      let index2 = 10;
+      let hash2 = extended_hash(salt, index2);
+      let digit2 = extended_triple(&hash2);
+      assert_eq!(Some('e'), digit2);
+  }
+
+}
+
+// Compute the md5 hash from the given salt and index, then determine 
+// whether the hex representation contains the same digit (0-9a-e) 
+// 3 times in a row.
+fn find_triple(salt: &str, index: u32) -> Option<char> {
+    let ss = format!("{}{}", salt, index);
+    let hash = md5::compute(ss);
+    let hash_str = format!("{:x}", hash);
+    extended_triple(&hash_str)
+}
+
+fn has_quintuple(salt: &str, index: u32, digit: char) -> bool {
+    let ss = format!("{}{}", salt, index);
+    let hash = md5::compute(ss);
+    let hash_str = format!("{:x}", hash);
+    quintuple_helper(&hash_str, digit)
+}
+
+fn quintuple_helper(hash: &str, digit: char) -> bool {
+    let q_str = format!("{}{}{}{}{}", digit, digit, digit, digit, digit);
+    hash.contains(&q_str)
+}
+
+fn is_key(salt: &str, index: u32) -> bool {
+    if let Some(digit) = find_triple(salt, index) {
+        for ii in index+1..index+1001 {
+            if has_quintuple(salt, ii, digit) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn key_index(salt: &str) -> u32 {
+    let mut key_count = 0;
+    let mut index = 0;
+    loop {
+        aoc_common::cancel::check();
+        if is_key(salt, index) {
+            key_count += 1;
+        }
+        if key_count == 64 {
+            return index;
+        }
+        index += 1;
+    }
+}
+
+
+fn extended_hash(salt: &str, index: u32) -> String {
+    // Each of these takes long enough to be worth checking for a timeout.
+    aoc_common::cancel::check();
+    let ss = format!("{}{}", salt, index);
+    let hash = md5::compute(ss);
+    let mut hash_str = format!("{:x}", hash);
+    for _ in 0..2016 {
+        let hash = md5::compute(hash_str);
+        hash_str = format!("{:x}", hash);
+    }
+    hash_str
+}
+
+fn extended_triple(hash: &str) -> Option<char> {
+    let hash_chars: Vec<char> = hash.chars().collect();
+    for ii in 0..hash_chars.len() - 2 {
+        if hash_chars[ii] == hash_chars[ii+1] && hash_chars[ii] == hash_chars[ii+2] {
+            return Some(hash_chars[ii]);
+        }
+    }
+    None
+}
+
+// Part1 computed a single md5 hash; Part 2 requires computing it 2017 times
+// for each index. So, I'm pretty sure that I should be storing those hashes 
+// in a HashMap rather than computing them. 
+// NB: This is still really slow. 
+fn stretched_key_index(salt: &str) -> u32 {
+    let mut key_count = 0;
+    let mut index = 0;
+    let mut hashes: HashMap<u32, String> = HashMap::new();
+    let mut triple_count = 0;
+    loop {
+        aoc_common::cancel::check();
+        let hash = hashes.entry(index).or_insert_with(|| extended_hash(salt, index));
+        if let Some(digit) = extended_triple(hash) {
+            triple_count += 1;
+            if triple_count % 100 == 0 {
+                log::debug!("{}-th triple at index {}", triple_count, index);
+            }
+            for ii in index+1..index+1001 {
+                let key_hash = hashes.entry(ii).or_insert_with(|| extended_hash(salt, ii));
+                if quintuple_helper(key_hash, digit) {
+                    key_count += 1;
+                }
+            }
+        }
+        if key_count == 64 {
+            return index;
+        }
+        index += 1;
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    // The salt that every hash is computed from.
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(single_line(input)?.to_string())
+    }
+
+    fn part1(salt: &Self::Input) -> u32 {
+        key_index(salt)
+    }
+
+    fn part2(salt: &Self::Input) -> u32 {
+        stretched_key_index(salt)
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_common::input::single_line;
+use aoc_common::{ParseError, Solution};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day01::parse("12a4").unwrap_err();
+        assert_eq!((1, 3), (err.line, err.column));
+        assert_eq!("a", err.text);
+        assert!(Day01::parse(" \n").is_err());
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let digits = single_line(input)?;
+        digits
+            .char_indices()
+            .map(|(idx, ch)| {
+                ch.to_digit(10).ok_or_else(|| {
+                    let text = &digits[idx..idx + ch.len_utf8()];
+                    ParseError::new(digits, text, "expected a digit")
+                })
+            })
+            .collect()
+    }
+
+    fn part1(digits: &Self::Input) -> u32 {
+        let mut sum: u32 = 0;
+        for i in 0..(digits.len() - 1) {
+            if digits[i] == digits[i+1] {
+                sum += digits[i];
+            }
+        }
+        if digits.len() >= 2 && digits[0] == digits[digits.len() - 1] {
+            sum += digits[0];
+        }
+        sum
+    }
+
+    fn part2(digits: &Self::Input) -> u32 {
+        let mut sum: u32 = 0;
+        let offset = digits.len() / 2;
+        for i in 0..digits.len() {
+            if digits[i] == digits[(i+offset)%digits.len()] {
+                sum += digits[i];
+            }
+        }
+        sum
+    }
+}
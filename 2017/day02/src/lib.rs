@@ -0,0 +1,120 @@
+use aoc_common::input::{parse_lines, parse_numbers};
+use aoc_common::{ParseError, Solution};
+use std::convert::TryFrom;
+use std::fmt;
+use itertools::Itertools;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day02::parse("5 1 9 5\n7 -5 3").unwrap_err();
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!("-5", err.text);
+        let err = Day02::parse("5 1\nfive").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_no_divisible_pair() {
+        let rows = Day02::parse("5 9 2 8\n7 5 3").unwrap();
+        assert_eq!(Checksum::NoDivisiblePair(2), Day02::part2(&rows));
+        assert_eq!("no evenly divisible cells in row 2", Day02::part2(&rows).to_string());
+    }
+}
+
+// Quotient of the only two cells in the row that divide evenly, if any.
+fn checksum_divides(row: &[i32]) -> Option<u32> {
+    let mut cells = row.to_vec();
+    cells.sort();
+    // for i in 0..(cells.len()-1) {
+    //     for j in (i+1)..cells.len() {
+    //         if cells[j] % cells[i] == 0 {
+    //             return u32::try_from(cells[j] / cells[i]).unwrap();
+    //         }
+    //     }
+    // }
+    for combo in cells.iter().combinations(2) {
+        if combo[1] % combo[0] == 0 {
+            return u32::try_from(combo[1] / combo[0]).ok();
+        }
+    }
+    None
+}
+
+fn checksum_line(cells: &[i32]) -> u32 {
+    let cs = cells.iter().max().unwrap() - cells.iter().min().unwrap();
+    u32::try_from(cs).unwrap()
+}
+
+// Part 2's answer, which only exists if every row has a pair of cells that
+// divide evenly.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Checksum {
+    Sum(u32),
+    // The (1-based) index of the first row without such a pair.
+    NoDivisiblePair(usize),
+}
+
+impl fmt::Display for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Checksum::Sum(sum) => write!(f, "{}", sum),
+            Checksum::NoDivisiblePair(row) => write!(f, "no evenly divisible cells in row {}", row),
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    // The spreadsheet, one Vec of cells per row.
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = u32;
+    type Answer2 = Checksum;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let cells: Vec<i32> = parse_numbers(line)?;
+            // Part 2 divides cells by each other.
+            for (x, cell) in line.split_whitespace().zip(cells.iter()) {
+                if *cell <= 0 {
+                    return Err(ParseError::new(line, x, "cells must be positive"));
+                }
+            }
+            if cells.is_empty() {
+                return Err(ParseError::new(line, line, "empty row"));
+            }
+            Ok(cells)
+        })
+    }
+
+    // Calculate the spreadsheet's checksum.
+    // For each row, determine the difference between the largest value and the
+    // smallest value; the checksum is the sum of all of these differences.
+    fn part1(rows: &Self::Input) -> u32 {
+        // let mut sum: u32 = 0;
+        // for row in rows {
+            // sum += checksum_line(row);
+        // }
+        // sum
+
+        rows.iter().map(|row| checksum_line(row)).sum()
+    }
+
+    fn part2(rows: &Self::Input) -> Checksum {
+        // The puzzle promises that every row has exactly one such pair, but
+        // part 1's example doesn't.
+        let mut sum = 0;
+        for (idx, row) in rows.iter().enumerate() {
+            match checksum_divides(row) {
+                Some(quotient) => sum += quotient,
+                None => return Checksum::NoDivisiblePair(idx + 1),
+            }
+        }
+        Checksum::Sum(sum)
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_common::input::single_line;
+use aoc_common::{ParseError, Point, Solution};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_get_ring() {
+        assert_eq!(0, get_ring(1));
+        assert_eq!(1, get_ring(2));
+        assert_eq!(1, get_ring(9));
+        assert_eq!(2, get_ring(25));
+        assert_eq!(3, get_ring(26));
+        assert_eq!(2, get_ring(15));
+        assert_eq!(1, get_ring(7));
+    
+    }
+
+
+    #[test]
+    fn test_get_neighbors() {
+        let origin = Point::ORIGIN;
+        let origin_neighbors = origin.neighbors8();
+        assert_eq!(8, origin_neighbors.len());
+        let pt1 = Point::new(1, 1);
+        assert!(origin_neighbors.contains(&pt1));
+        let pt2 = Point::new(0, 0);
+        assert!(!origin_neighbors.contains(&pt2));
+    }
+
+}
+
+// Return zero-indexed ring for input
+fn get_ring(val: i32) -> i32 {
+    // The values in the nth ring will be between
+    // (2n-1)^2 + 1 
+    // and 
+    // (2n+1)^2
+    // inclusive
+    // (Ring counts start at 0)
+    if 1 == val {
+        return 0
+    }
+    let mut ring: i32 = 0;
+    while (2*ring+1).pow(2) < val {
+        ring += 1;
+    }
+    ring
+}
+
+fn get_coords(val: i32, ring: i32) -> Point {
+    // (n, 0) Center of right side will be: (2n-1)^2 + n
+    let center_right = (2*ring-1).pow(2) + ring;
+    // (n, n) Top-right corner will be: (2n-1)^2 + 2n
+    let top_right = (2*ring-1).pow(2) + 2*ring;
+
+    // (0, n) Center of top will be: (2n-1)^2 + 3n
+    let center_top = (2*ring-1).pow(2) + 3*ring;
+    let top_left = (2*ring-1).pow(2) + 4*ring;
+
+    let center_left = (2*ring-1).pow(2) + 5*ring;
+    let bottom_left = (2*ring-1).pow(2) + 6*ring;
+    let center_bottom = (2*ring-1).pow(2) + 7*ring;
+    
+    // (n, -n) Bottom-right corner will be (2n+1) ^2
+    // let bottom_right = (2*ring+1).pow(2);
+    
+    let xx: i32; 
+    let yy: i32;
+    if val < top_right {
+        xx = ring;
+        yy = val  - center_right;
+    } else if val < top_left {
+        xx = center_top - val;
+        yy = ring;
+    } else if val < bottom_left {
+        xx = -ring;
+        yy = center_left - val;
+    } else {
+        xx = val - center_bottom;
+        yy = -ring;
+    }
+    Point::new(xx, yy)
+}
+
+
+fn manhattan_distance(num: i32) -> i32 {
+    let ring = get_ring(num);
+    let pt = get_coords(num, ring);
+    pt.manhattan_distance(Point::ORIGIN)
+}
+
+fn first_larger_sum(num: i32) -> i32 {
+    /*
+    Reuse our solution to part 1 to determine the coordinates of cells in order.
+    Keep a HashSet of already-visited coordinates and their cumulative sums,
+    and use that to look up values for already-visited neighbors of all new cells.
+    */
+    let mut cells = HashMap::new();
+    cells.insert(Point::ORIGIN, 1);
+    // Sum of all neighbors in previous cell.
+    let mut neighbor_sum = 1;
+    // We are about to visit the n-th cell
+    let mut cell_idx = 2;
+
+    while neighbor_sum < num {
+        let ring = get_ring(cell_idx);
+        let coords = get_coords(cell_idx, ring);
+        let neighbors = coords.neighbors8();
+        let mut sum = 0;  // Sum of all already-visited neighbors
+        for neighbor in neighbors.iter() {
+            let nn = cells.get(neighbor);
+            if let Some(val) = nn {
+                sum += val;
+            } 
+        }
+        assert!(sum >= neighbor_sum);
+        neighbor_sum = sum;
+        cell_idx += 1;
+        cells.insert(coords, sum);
+    }
+    neighbor_sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    // The square whose data we need to carry to the access port.
+    type Input = i32;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let num = single_line(input)?;
+        num.parse().map_err(|err| ParseError::new(num, num, err))
+    }
+
+    fn part1(num: &Self::Input) -> i32 {
+        manhattan_distance(*num)
+    }
+
+    fn part2(num: &Self::Input) -> i32 {
+        first_larger_sum(*num)
+    }
+}
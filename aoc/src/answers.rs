@@ -0,0 +1,99 @@
+//! Registry of known-correct answers, checked in at the top of the repository
+//! as answers.toml and used by `aoc verify`.
+//!
+//! Each day is a table keyed by year and day, with an optional string for
+//! each part:
+//!
+//! ```toml
+//! [2016.day01]
+//! part1 = "271"
+//! part2 = "153"
+//! ```
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            r#"
+[2016.day01]
+part1 = "271"
+
+[2016.day08]
+part2 = """
+# #
+ # """
+"#,
+        )
+        .unwrap();
+        assert_eq!(Some("271"), answers.get(2016, 1, 1));
+        assert_eq!(None, answers.get(2016, 1, 2));
+        assert_eq!(Some("# #\n # "), answers.get(2016, 8, 2));
+        assert_eq!(None, answers.get(2017, 1, 1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Answers::parse("[2016.day01]\npart3 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_checked_in_answers() {
+        // The registry that `aoc verify` uses needs to stay loadable.
+        Answers::load(&default_path()).unwrap();
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    // year -> "dayNN" -> answers
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+
+pub fn default_path() -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", "answers.toml"]
+        .iter()
+        .collect()
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers, String> {
+        let years = toml::from_str(input).map_err(|err| err.to_string())?;
+        Ok(Answers { years })
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let input = std::fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read answers {}: {}", path.display(), err))?;
+        Answers::parse(&input)
+            .map_err(|err| format!("Unable to parse answers {}: {}", path.display(), err))
+    }
+
+    /// The recorded answer for one part of a puzzle, if there is one.
+    pub fn get(&self, year: u32, day: u32, part: u8) -> Option<&str> {
+        let day_answers = self
+            .years
+            .get(&year.to_string())?
+            .get(&format!("day{:02}", day))?;
+        let answer = match part {
+            1 => &day_answers.part1,
+            2 => &day_answers.part2,
+            _ => &None,
+        };
+        answer.as_deref()
+    }
+}
@@ -0,0 +1,183 @@
+//! `aoc bench`: times parsing and each part of the selected puzzles over
+//! several runs, and writes the results to a JSON file so that they can be
+//! compared before and after an optimisation.
+//!
+//! The results file has one entry per puzzle and stage:
+//!
+//! ```json
+//! {
+//!   "runs": 10,
+//!   "results": [
+//!     { "year": 2016, "day": 1, "stage": "parse", "min_ns": 5120, "median_ns": 5340, "max_ns": 9870 }
+//!   ]
+//! }
+//! ```
+
+use crate::{load_input, Progress, Puzzle};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&millis(&[5, 1, 3]));
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(Duration::from_millis(5), stats.max);
+
+        // With an even number of runs, the median is halfway between the
+        // middle two.
+        let stats = Stats::new(&millis(&[4, 1, 2, 8]));
+        assert_eq!(Duration::from_millis(3), stats.median);
+
+        let stats = Stats::new(&millis(&[7]));
+        assert_eq!(stats.min, stats.max);
+    }
+
+    #[test]
+    fn test_results_json() {
+        let results = BenchResults {
+            runs: 1,
+            results: vec![StageResult::new(2016, 1, "part1", Stats::new(&millis(&[2])))],
+        };
+        let json: serde_json::Value = serde_json::from_str(&results.to_json()).unwrap();
+        assert_eq!(1, json["runs"]);
+        assert_eq!("part1", json["results"][0]["stage"]);
+        assert_eq!(2_000_000, json["results"][0]["median_ns"]);
+    }
+}
+
+/// Fastest, median and slowest time taken by one stage.
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // Panics if there are no samples; every stage is run at least once.
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        Stats {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct StageResult {
+    year: u32,
+    day: u32,
+    stage: String,
+    min_ns: u64,
+    median_ns: u64,
+    max_ns: u64,
+}
+
+impl StageResult {
+    fn new(year: u32, day: u32, stage: &str, stats: Stats) -> StageResult {
+        StageResult {
+            year,
+            day,
+            stage: stage.to_string(),
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            max_ns: stats.max.as_nanos() as u64,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct BenchResults {
+    runs: usize,
+    results: Vec<StageResult>,
+}
+
+impl BenchResults {
+    fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("benchmark results are always serializable")
+    }
+}
+
+pub fn default_output_path() -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", "target", "bench.json"]
+        .iter()
+        .collect()
+}
+
+// Runs a single puzzle `runs` times, returning the time taken by each stage
+// on every run, in the order that the stages happen.
+fn time_puzzle(puzzle: &Puzzle, input: &str, runs: usize) -> Result<Vec<(String, Vec<Duration>)>, String> {
+    let mut samples: Vec<(String, Vec<Duration>)> = Vec::new();
+    for _ in 0..runs {
+        (puzzle.run)(input, None, &mut |progress, elapsed| {
+            let stage = match progress {
+                Progress::Parsed => String::from("parse"),
+                Progress::Solved(part, _) => format!("part{}", part),
+            };
+            match samples.iter_mut().find(|(name, _)| *name == stage) {
+                Some((_, durations)) => durations.push(elapsed),
+                None => samples.push((stage, vec![elapsed])),
+            }
+        })
+        .map_err(|err| format!("unable to parse input: {}", err))?;
+    }
+    Ok(samples)
+}
+
+pub fn bench<'a>(puzzles: impl Iterator<Item = &'a Puzzle>, runs: usize, output: &Path) -> Result<(), String> {
+    let mut results = BenchResults {
+        runs,
+        results: Vec::new(),
+    };
+    for puzzle in puzzles {
+        let label = format!("{} day {:02}", puzzle.year, puzzle.day);
+        let samples = load_input(puzzle, None).and_then(|input| time_puzzle(puzzle, &input, runs));
+        let samples = match samples {
+            Ok(samples) => samples,
+            Err(err) => {
+                println!("{}: skipped ({})", label, err);
+                continue;
+            }
+        };
+        for (stage, durations) in samples {
+            let stats = Stats::new(&durations);
+            println!(
+                "{} {:<5}  min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+                label, stage, stats.min, stats.median, stats.max
+            );
+            results
+                .results
+                .push(StageResult::new(puzzle.year, puzzle.day, &stage, stats));
+        }
+    }
+
+    if let Some(dir) = output.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("Unable to create {}: {}", dir.display(), err))?;
+    }
+    std::fs::write(output, results.to_json())
+        .map_err(|err| format!("Unable to write results {}: {}", output.display(), err))?;
+    println!();
+    println!("Results written to {}", output.display());
+    Ok(())
+}
@@ -0,0 +1,366 @@
+//! `aoc examples <year> <day> <page>`: turns the worked examples in a saved
+//! copy of a puzzle's page into fixtures (see `fixtures`), rather than
+//! copying them out by hand.
+//!
+//! Every `<pre><code>` block on the page becomes a fixture input, and the
+//! answers that the puzzle text emphasises (`<code><em>...</em></code>`) are
+//! used to guess the expected answers: when a part's description has a
+//! single example, it's given the last answer emphasised in that part, and a
+//! part 2 without examples of its own is assumed to reuse the last one from
+//! part 1. Not every block is really an example input, and not every guess
+//! is right, so everything else that was emphasised is left as a comment in
+//! answers.toml to check against the puzzle.
+//!
+//! Nothing that's already there is overwritten: blocks that match an
+//! existing fixture are skipped, and new tables are added to the end of
+//! answers.toml.
+
+use crate::fixtures::{self, Fixture};
+use std::path::Path;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 13: A Maze of Twisty Little Cubicles ---</h2>
+<p>For example, if the favorite number were <code>10</code>:</p>
+<pre><code>favorite_number = 10
+target = 7,4
+</code></pre>
+<p>Drawing walls as <code>#</code>, it would take <code><em>11</em></code> steps; <em>not</em> 12.</p>
+</article>
+<p>Your puzzle answer was <code>86</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>This time, <em><code>x &lt; 3 &amp;&amp; y &gt; 2</code></em> gets <code><em>5</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse_page() {
+        let parts = parse_page(PAGE);
+        assert_eq!(2, parts.len());
+        assert_eq!(vec!["favorite_number = 10\ntarget = 7,4\n"], parts[0].examples);
+        assert_eq!(vec!["11"], parts[0].answers);
+        assert_eq!(Vec::<String>::new(), parts[1].examples);
+        assert_eq!(vec!["x < 3 && y > 2", "5"], parts[1].answers);
+    }
+
+    #[test]
+    fn test_plan() {
+        let new = plan(&parse_page(PAGE), &[]);
+        assert_eq!(1, new.len());
+        assert_eq!("example", new[0].name);
+        assert_eq!(Some("11"), new[0].part1.as_deref());
+        assert_eq!(Some("5"), new[0].part2.as_deref());
+        assert_eq!(
+            "[example]\npart1 = \"11\"\npart2 = \"5\"\n# Also emphasised: \"x < 3 && y > 2\"\n",
+            new[0].answers_entry()
+        );
+
+        // Already extracted, so there's nothing to do.
+        let existing = vec![(String::from("example"), new[0].input.clone())];
+        assert!(plan(&parse_page(PAGE), &existing).is_empty());
+    }
+
+    #[test]
+    fn test_names() {
+        let parts = vec![Part {
+            examples: vec![String::from("R2, L3"), String::from("a\nb\n"), String::from("c\nd\n")],
+            answers: vec![],
+        }];
+        let existing = vec![(String::from("example"), String::from("x\n"))];
+        let names: Vec<String> = plan(&parts, &existing).into_iter().map(|new| new.name).collect();
+        assert_eq!(vec!["r2_l3", "example2", "example3"], names);
+        assert_eq!("\"2x3\"", toml_key("2x3"));
+        assert_eq!("a\u{b}b", unescape("a&#11;b"));
+    }
+}
+
+// What one part's description has to offer.
+#[derive(Debug)]
+struct Part {
+    examples: Vec<String>,
+    answers: Vec<String>,
+}
+
+// A fixture that's about to be written.
+#[derive(Debug)]
+struct NewFixture {
+    name: String,
+    input: String,
+    part1: Option<String>,
+    part2: Option<String>,
+    // Emphasised answers that weren't used as part1 or part2.
+    others: Vec<String>,
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let ch = match entity {
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("amp") => Some('&'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some(code) if code.starts_with('#') => code[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        };
+        match (ch, entity) {
+            (Some(ch), Some(entity)) => {
+                unescaped.push(ch);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => (),
+        }
+    }
+    text
+}
+
+// The contents of every (non-nested) `<tag>` element in `html`, in order.
+fn elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+        // Make sure that this is the whole tag name, e.g. not <preview>.
+        if !rest.starts_with(['>', ' ', '\t', '\n']) {
+            continue;
+        }
+        let Some(content_start) = rest.find('>') else {
+            break;
+        };
+        rest = &rest[content_start + 1..];
+        let Some(end) = rest.find(&close) else {
+            break;
+        };
+        found.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    found
+}
+
+// If `html` is just a single `<tag>` element, its contents.
+fn only_element<'a>(html: &'a str, tag: &str) -> Option<&'a str> {
+    let html = html.trim();
+    html.strip_prefix(&format!("<{}>", tag))?
+        .strip_suffix(&format!("</{}>", tag))
+}
+
+fn parse_part(html: &str) -> Part {
+    let examples = elements(html, "pre")
+        .into_iter()
+        .filter_map(|pre| only_element(pre, "code"))
+        .map(|code| unescape(&strip_tags(code)))
+        .collect();
+
+    // Answers show up as both <code><em>1</em></code> and
+    // <em><code>1</code></em>, and need to be kept in the order that they
+    // appear in.
+    let mut answers = Vec::new();
+    let mut rest = html;
+    loop {
+        let next = ["<code><em>", "<em><code>"]
+            .iter()
+            .filter_map(|open| rest.find(open).map(|start| (start, *open)))
+            .min();
+        let Some((start, open)) = next else {
+            break;
+        };
+        let close = if open == "<code><em>" { "</em></code>" } else { "</code></em>" };
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+        answers.push(unescape(&strip_tags(&rest[..end])));
+        rest = &rest[end + close.len()..];
+    }
+    Part { examples, answers }
+}
+
+// Each part of the puzzle is described in its own <article>. An unanswered
+// puzzle only has the first one.
+fn parse_page(html: &str) -> Vec<Part> {
+    let articles = elements(html, "article");
+    if articles.is_empty() {
+        return vec![parse_part(html)];
+    }
+    articles.into_iter().map(parse_part).collect()
+}
+
+// Short one-line examples are named after their contents, like the ones
+// that were written by hand, and everything else is just an example.
+fn fixture_name(input: &str, taken: &[String]) -> String {
+    let slug: Vec<String> = input
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let slug = slug.join("_");
+    if !input.trim().contains('\n') && !slug.is_empty() && slug.len() <= 30 && !taken.contains(&slug) {
+        return slug;
+    }
+    (1..)
+        .map(|idx| if idx == 1 { String::from("example") } else { format!("example{}", idx) })
+        .find(|name| !taken.contains(name))
+        .unwrap()
+}
+
+// Works out which fixtures to add, given the `existing` fixtures' names and
+// inputs.
+fn plan(parts: &[Part], existing: &[(String, String)]) -> Vec<NewFixture> {
+    let mut taken: Vec<String> = existing.iter().map(|(name, _)| name.clone()).collect();
+    let mut new: Vec<NewFixture> = Vec::new();
+    // Where part 2's answer goes if it doesn't have any examples of its own.
+    let mut last_part1 = None;
+    for (part_idx, part) in parts.iter().take(2).enumerate() {
+        let guess = if part.examples.len() == 1 { part.answers.last() } else { None };
+        for example in &part.examples {
+            let same = |input: &String| input.trim_end() == example.trim_end();
+            if existing.iter().any(|(_, input)| same(input)) || new.iter().any(|new| same(&new.input)) {
+                continue;
+            }
+            let name = fixture_name(example, &taken);
+            taken.push(name.clone());
+            new.push(NewFixture {
+                name,
+                input: example.clone(),
+                part1: None,
+                part2: None,
+                others: Vec::new(),
+            });
+            let fixture = new.last_mut().unwrap();
+            if part_idx == 0 {
+                fixture.part1 = guess.cloned();
+                last_part1 = Some(new.len() - 1);
+            } else {
+                fixture.part2 = guess.cloned();
+            }
+        }
+        if part_idx == 1 && part.examples.is_empty() {
+            if let (Some(idx), Some(answer)) = (last_part1, part.answers.last()) {
+                new[idx].part2 = Some(answer.clone());
+            }
+        }
+    }
+
+    // Anything that wasn't used is still worth a look.
+    let answers: Vec<&String> = parts.iter().flat_map(|part| part.answers.iter()).collect();
+    for fixture in new.iter_mut() {
+        fixture.others = answers
+            .iter()
+            .filter(|answer| Some(**answer) != fixture.part1.as_ref() && Some(**answer) != fixture.part2.as_ref())
+            .map(|answer| answer.to_string())
+            .collect();
+    }
+    new
+}
+
+// Keys that start with a digit are quoted, like in the hand-written files.
+fn toml_key(name: &str) -> String {
+    if name.starts_with(|ch: char| ch.is_ascii_digit()) {
+        format!("{:?}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+impl NewFixture {
+    fn answers_entry(&self) -> String {
+        let mut entry = format!("[{}]\n", toml_key(&self.name));
+        for (part, answer) in [(1, &self.part1), (2, &self.part2)] {
+            if let Some(answer) = answer {
+                entry.push_str(&format!("part{} = {:?}\n", part, answer));
+            }
+        }
+        if !self.others.is_empty() {
+            let others: Vec<String> = self.others.iter().map(|other| format!("{:?}", other)).collect();
+            entry.push_str(&format!("# Also emphasised: {}\n", others.join(", ")));
+        }
+        entry
+    }
+}
+
+// The names and inputs of the fixtures that are already in `dir`.
+fn existing_fixtures(dir: &Path) -> Result<Vec<(String, String)>, String> {
+    let fixtures = fixtures::load(dir)?;
+    fixtures
+        .into_iter()
+        .map(|fixture| {
+            let Fixture { name, input_path, .. } = fixture;
+            std::fs::read_to_string(&input_path)
+                .map(|input| (name, input))
+                .map_err(|err| format!("Unable to read {}: {}", input_path.display(), err))
+        })
+        .collect()
+}
+
+pub fn extract(year: u32, day: u32, page: &Path, dry_run: bool) -> Result<(), String> {
+    let html = std::fs::read_to_string(page).map_err(|err| format!("Unable to read {}: {}", page.display(), err))?;
+    let dir = fixtures::fixtures_dir(year, day);
+    let day_dir = dir.parent().unwrap();
+    if !day_dir.is_dir() {
+        return Err(format!("There's no {} yet; see `aoc new {} {}`", day_dir.display(), year, day));
+    }
+
+    let new = plan(&parse_page(&html), &existing_fixtures(&dir)?);
+    if new.is_empty() {
+        println!("No new examples in {}", page.display());
+        return Ok(());
+    }
+
+    let answers_path = dir.join("answers.toml");
+    let mut answers = match std::fs::read_to_string(&answers_path) {
+        Ok(answers) => answers,
+        Err(_) => String::from("# Expected answers for the examples in this directory; see aoc/src/fixtures.rs.\n"),
+    };
+    for fixture in &new {
+        let input_path = dir.join(format!("{}.txt", fixture.name));
+        println!("{}:\n{}", input_path.display(), fixture.input.trim_end());
+        println!("{}", fixture.answers_entry());
+        answers.push('\n');
+        answers.push_str(&fixture.answers_entry());
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&dir).map_err(|err| format!("Unable to create {}: {}", dir.display(), err))?;
+    for fixture in &new {
+        let input_path = dir.join(format!("{}.txt", fixture.name));
+        // Like the hand-written ones, every fixture ends with a newline.
+        let input = format!("{}\n", fixture.input.trim_end_matches('\n'));
+        std::fs::write(&input_path, input)
+            .map_err(|err| format!("Unable to write {}: {}", input_path.display(), err))?;
+    }
+    std::fs::write(&answers_path, answers)
+        .map_err(|err| format!("Unable to write {}: {}", answers_path.display(), err))?;
+    println!("Added {} examples; check their answers in {}", new.len(), answers_path.display());
+    Ok(())
+}
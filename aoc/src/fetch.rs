@@ -0,0 +1,191 @@
+//! `aoc fetch`: downloads a day's puzzle input.
+//!
+//! Inputs are different for every account, so downloading one needs the
+//! `session` cookie from a logged-in browser, passed with `--session` or in
+//! the `AOC_SESSION` environment variable. Each input is downloaded once
+//! into a local cache (by default .aoc-cache at the top of this repository,
+//! which is ignored by git) and then copied to the day's input.txt, if the
+//! day exists and doesn't have one yet. Inputs never change, so anything
+//! already in the cache is never downloaded again.
+//!
+//! The site can be changed with `--base-url` or `AOC_BASE_URL`, which is how
+//! the tests run against a local stub rather than the real thing.
+
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Serves `response` to a single request, returning the request's
+    // headers once it's been answered.
+    fn stub_server(response: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    fn temp_cache(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let (base_url, server) = stub_server("HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n1\n2\n3\n");
+        let config = FetchConfig {
+            base_url,
+            session: Some(String::from("abc123")),
+            cache_dir: temp_cache("once"),
+        };
+        let path = fetch_input(&config, 2016, 3).unwrap();
+        assert_eq!(config.cache_dir.join("2016").join("day03.txt"), path);
+        assert_eq!("1\n2\n3\n", std::fs::read_to_string(&path).unwrap());
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2016/day/3/input HTTP/1.1\r\n"));
+        assert!(request.contains("session=abc123"));
+
+        // The stub has gone away now, so this only works because the input
+        // is cached.
+        assert_eq!(path, fetch_input(&config, 2016, 3).unwrap());
+        std::fs::remove_dir_all(&config.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, server) = stub_server("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        let mut config = FetchConfig {
+            base_url,
+            session: None,
+            cache_dir: temp_cache("errors"),
+        };
+        assert!(fetch_input(&config, 2016, 3).unwrap_err().contains("AOC_SESSION"));
+
+        config.session = Some(String::from("abc123"));
+        let err = fetch_input(&config, 2016, 3).unwrap_err();
+        assert!(err.contains("404"), "{}", err);
+        server.join().unwrap();
+        // Nothing gets cached when the download fails.
+        assert!(!cache_path(&config.cache_dir, 2016, 3).exists());
+        let _ = std::fs::remove_dir_all(&config.cache_dir);
+    }
+}
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Where to download inputs from, and where to keep them.
+pub struct FetchConfig {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+}
+
+impl FetchConfig {
+    /// Fills in anything that wasn't given on the command line from the
+    /// environment, and then from the defaults.
+    pub fn new(base_url: Option<String>, session: Option<String>, cache_dir: Option<PathBuf>) -> FetchConfig {
+        let env = |name| std::env::var(name).ok().filter(|value: &String| !value.trim().is_empty());
+        FetchConfig {
+            base_url: base_url
+                .or_else(|| env("AOC_BASE_URL"))
+                .unwrap_or_else(|| String::from(DEFAULT_BASE_URL)),
+            session: session.or_else(|| env("AOC_SESSION")),
+            cache_dir: cache_dir
+                .or_else(|| env("AOC_CACHE_DIR").map(PathBuf::from))
+                .unwrap_or_else(default_cache_dir),
+        }
+    }
+}
+
+fn default_cache_dir() -> PathBuf {
+    crate::scaffold::repo_root().join(".aoc-cache")
+}
+
+pub fn cache_path(cache_dir: &Path, year: u32, day: u32) -> PathBuf {
+    cache_dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+}
+
+fn download(config: &FetchConfig, year: u32, day: u32) -> Result<String, String> {
+    let session = config
+        .session
+        .as_ref()
+        .ok_or("No session token; pass --session or set AOC_SESSION")?;
+    let url = format!("{}/{}/day/{}/input", config.base_url.trim_end_matches('/'), year, day);
+    log::info!("Downloading {}", url);
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session.trim()))
+        .set("User-Agent", "github.com/lindzey/AdventOfCode aoc fetch")
+        .call()
+        .map_err(|err| match err {
+            // The site answers with a 400 when the session has expired.
+            ureq::Error::Status(code @ 400..=403, _) => {
+                format!("Unable to download {}: status {} (is the session token still valid?)", url, code)
+            }
+            ureq::Error::Status(code, _) => format!("Unable to download {}: status {}", url, code),
+            // These already say which URL they were for.
+            err => format!("Unable to download {}", err),
+        })?;
+    response
+        .into_string()
+        .map_err(|err| format!("Unable to download {}: {}", url, err))
+}
+
+/// Makes sure that the input for the given day is in the cache, downloading
+/// it if it isn't, and returns its path.
+pub fn fetch_input(config: &FetchConfig, year: u32, day: u32) -> Result<PathBuf, String> {
+    let path = cache_path(&config.cache_dir, year, day);
+    if path.exists() {
+        log::info!("Using cached input {}", path.display());
+        return Ok(path);
+    }
+    let input = download(config, year, day)?;
+
+    let dir = path.parent().unwrap();
+    std::fs::create_dir_all(dir).map_err(|err| format!("Unable to create {}: {}", dir.display(), err))?;
+    // Write to a temporary file first, so that an interrupted write doesn't
+    // leave half an input in the cache.
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, input)
+        .and_then(|_| std::fs::rename(&partial, &path))
+        .map_err(|err| format!("Unable to write {}: {}", path.display(), err))?;
+    Ok(path)
+}
+
+pub fn fetch(config: &FetchConfig, year: u32, day: u32) -> Result<(), String> {
+    let cached = fetch_input(config, year, day)?;
+    println!("Input for {} day {} is in {}", year, day, cached.display());
+
+    let input_path = crate::default_input_path(year, day);
+    let day_dir = input_path.parent().unwrap();
+    if !day_dir.is_dir() {
+        println!("There's no {} yet; see `aoc new {} {}`", day_dir.display(), year, day);
+    } else if input_path.exists() {
+        println!("Leaving the existing {} alone", input_path.display());
+    } else {
+        std::fs::copy(&cached, &input_path)
+            .map_err(|err| format!("Unable to write {}: {}", input_path.display(), err))?;
+        println!("Copied it to {}", input_path.display());
+    }
+    Ok(())
+}
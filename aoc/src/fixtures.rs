@@ -0,0 +1,156 @@
+//! Worked examples from the puzzle descriptions, kept as data next to each
+//! day so that adding one doesn't need any new test code. They're checked
+//! by `aoc verify --examples`, and by this crate's tests.
+//!
+//! Each example is an input file in the day's fixtures directory, e.g.
+//! 2016/day13/fixtures/example.txt, with its expected answers in that
+//! directory's answers.toml, keyed by the file's name:
+//!
+//! ```toml
+//! [example]
+//! part1 = "11"
+//!
+//! # Too slow to check by default; see `aoc verify --examples --slow`
+//! [abc]
+//! part1 = "22728"
+//! slow = true
+//! ```
+//!
+//! Only the parts with an answer are checked, since many examples only
+//! apply to one of the parts. An example with an empty table (as created by
+//! `aoc new`) is waiting for its answers to be filled in.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_combine() {
+        let answers = parse_answers("[one]\npart1 = \"1\"\n\n[two]\npart2 = \"2\"\nslow = true").unwrap();
+        let dir = Path::new("fixtures");
+        let fixtures = combine(dir, vec![String::from("two"), String::from("one")], answers).unwrap();
+        assert_eq!(2, fixtures.len());
+        assert_eq!("one", fixtures[0].name);
+        assert_eq!(dir.join("one.txt"), fixtures[0].input_path);
+        assert_eq!(Some("1"), fixtures[0].part1.as_deref());
+        assert!(!fixtures[0].slow);
+        assert_eq!(None, fixtures[1].part1);
+        assert!(fixtures[1].slow);
+    }
+
+    #[test]
+    fn test_mismatched_fixtures() {
+        let answers = || parse_answers("[one]\npart1 = \"1\"").unwrap();
+        // An input without answers, or answers without an input, is most
+        // likely a typo in one of the names.
+        assert!(combine(Path::new("."), vec![String::from("two")], answers()).is_err());
+        assert!(combine(Path::new("."), vec![], answers()).is_err());
+        assert!(parse_answers("[one]\npart3 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::verify_examples(None, None, false).unwrap();
+    }
+
+    #[test]
+    #[ignore]
+    fn test_slow_examples() {
+        crate::verify_examples(None, None, true).unwrap();
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixtureAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+    #[serde(default)]
+    slow: bool,
+}
+
+/// A single worked example for one of the days.
+#[derive(Debug)]
+pub struct Fixture {
+    pub name: String,
+    pub input_path: PathBuf,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub slow: bool,
+}
+
+pub fn fixtures_dir(year: u32, day: u32) -> PathBuf {
+    [
+        env!("CARGO_MANIFEST_DIR"),
+        "..",
+        &year.to_string(),
+        &format!("day{:02}", day),
+        "fixtures",
+    ]
+    .iter()
+    .collect()
+}
+
+fn parse_answers(input: &str) -> Result<BTreeMap<String, FixtureAnswers>, String> {
+    toml::from_str(input).map_err(|err| err.to_string())
+}
+
+// Pairs up the input files with their answers, making sure that neither
+// is missing.
+fn combine(
+    dir: &Path,
+    names: Vec<String>,
+    mut answers: BTreeMap<String, FixtureAnswers>,
+) -> Result<Vec<Fixture>, String> {
+    let mut fixtures = Vec::new();
+    for name in names {
+        let answer = answers
+            .remove(&name)
+            .ok_or_else(|| format!("No answers for example {}", name))?;
+        fixtures.push(Fixture {
+            input_path: dir.join(format!("{}.txt", name)),
+            name,
+            part1: answer.part1,
+            part2: answer.part2,
+            slow: answer.slow,
+        });
+    }
+    if let Some(name) = answers.keys().next() {
+        return Err(format!("No input for example {} ({}.txt)", name, name));
+    }
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+/// Loads every example in `dir`. A day without a fixtures directory just
+/// doesn't have any examples.
+pub fn load(dir: &Path) -> Result<Vec<Fixture>, String> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let answers_path = dir.join("answers.toml");
+    let answers = std::fs::read_to_string(&answers_path)
+        .map_err(|err| format!("Unable to read {}: {}", answers_path.display(), err))?;
+    let answers = parse_answers(&answers)
+        .map_err(|err| format!("Unable to parse {}: {}", answers_path.display(), err))?;
+
+    let entries = std::fs::read_dir(dir)
+        .map_err(|err| format!("Unable to read {}: {}", dir.display(), err))?;
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("Unable to read {}: {}", dir.display(), err))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    combine(dir, names, answers).map_err(|err| format!("{}: {}", dir.display(), err))
+}
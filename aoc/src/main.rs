@@ -0,0 +1,610 @@
+use aoc_common::cancel::{self, CancelToken, Cancelled};
+use aoc_common::{logging, visualize, ParseError, Solution, Verbosity};
+use answers::Answers;
+use clap::{Parser, Subcommand};
+use output::{AnswerRecord, OutputFormat};
+use std::any::Any;
+use std::panic;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+mod answers;
+mod bench;
+mod extract;
+mod fetch;
+mod fixtures;
+mod output;
+mod run_all;
+mod scaffold;
+
+/// Run the Advent of Code solutions in this repository.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How much diagnostic output to show on stderr: quiet, info, debug or trace
+    #[arg(long, global = true, default_value_t = Verbosity::Quiet)]
+    log: Verbosity,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single puzzle and print its answers, or run many at once with
+    /// --all or --year and print a summary
+    Run {
+        /// Puzzle year, e.g. 2016
+        #[arg(required_unless_present = "batch", conflicts_with = "batch")]
+        year: Option<u32>,
+        /// Puzzle day, 1-25
+        #[arg(required_unless_present = "batch", conflicts_with = "batch")]
+        day: Option<u32>,
+        /// Only run this part; both parts are run by default
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input; defaults to <year>/dayNN/input.txt in this repository
+        #[arg(long, conflicts_with = "batch")]
+        input: Option<PathBuf>,
+        /// Also print how long parsing and each part took
+        #[arg(long)]
+        time: bool,
+        /// How to print the answers: text, or json with one object per
+        /// answer (always including timings)
+        #[arg(long, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Run every puzzle in parallel
+        #[arg(long, group = "batch")]
+        all: bool,
+        /// Run every puzzle from this year in parallel
+        #[arg(long = "year", id = "only_year", value_name = "YEAR", group = "batch")]
+        only_year: Option<u32>,
+        /// How many puzzles to run at once with --all or --year; defaults to
+        /// the number of CPUs
+        #[arg(long, requires = "batch", value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+        /// Give up on a puzzle after this many seconds; defaults to 60 with
+        /// --all or --year, and no limit otherwise. Puzzles that never check
+        /// for cancellation can't be stopped: they run to the end, and with
+        /// --all or --year they keep running in the background without
+        /// counting towards --jobs
+        #[arg(long)]
+        timeout: Option<u64>,
+        /// Animate how the solution works, for the days that support it
+        #[arg(long, conflicts_with = "batch")]
+        visualize: bool,
+        /// Milliseconds to show each frame of the animation for
+        #[arg(long, requires = "visualize", default_value_t = 100)]
+        frame_delay: u64,
+    },
+    /// Check every solution against the known answers, or against the
+    /// worked examples with --examples
+    Verify {
+        /// Only verify puzzles from this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only verify this day (of every year, unless --year is given)
+        #[arg(long)]
+        day: Option<u32>,
+        /// Answers registry; defaults to answers.toml in this repository
+        #[arg(long, conflicts_with = "examples")]
+        answers: Option<PathBuf>,
+        /// Check the examples in each day's fixtures directory instead
+        #[arg(long)]
+        examples: bool,
+        /// Include examples that are marked as slow
+        #[arg(long, requires = "examples")]
+        slow: bool,
+    },
+    /// Create the crate for a new day and register it with the runner
+    New {
+        /// Puzzle year, e.g. 2016
+        year: u32,
+        /// Puzzle day, 1-25
+        day: u32,
+    },
+    /// Download a day's puzzle input, unless it's already been downloaded
+    Fetch {
+        /// Puzzle year, e.g. 2016
+        year: u32,
+        /// Puzzle day, 1-25
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// Site to download from; defaults to $AOC_BASE_URL, or
+        /// https://adventofcode.com
+        #[arg(long)]
+        base_url: Option<String>,
+        /// Value of the site's session cookie; defaults to $AOC_SESSION
+        #[arg(long)]
+        session: Option<String>,
+        /// Where downloaded inputs are kept; defaults to $AOC_CACHE_DIR, or
+        /// .aoc-cache in this repository
+        #[arg(long)]
+        cache_dir: Option<PathBuf>,
+    },
+    /// Add the worked examples from a saved copy of a puzzle's page to the
+    /// day's fixtures
+    Examples {
+        /// Puzzle year, e.g. 2016
+        year: u32,
+        /// Puzzle day, 1-25
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// The puzzle's page, saved from a browser
+        page: PathBuf,
+        /// Only print what would be added
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Time parsing and each part of the solutions over several runs
+    Bench {
+        /// Only benchmark puzzles from this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only benchmark this day (of every year, unless --year is given)
+        #[arg(long)]
+        day: Option<u32>,
+        /// How many times to run each puzzle
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Where to write the results as JSON; defaults to target/bench.json
+        /// in this repository
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+// A stage of running a puzzle that has just finished.
+enum Progress {
+    Parsed,
+    Solved(u8, String),
+}
+
+// Parses the input and solves the requested part(s) of a single puzzle,
+// handing over each answer, and how long each stage took, as soon as it is
+// available.
+type RunFn = fn(&str, Option<u8>, &mut dyn FnMut(Progress, Duration)) -> Result<(), ParseError>;
+
+struct Puzzle {
+    year: u32,
+    day: u32,
+    run: RunFn,
+}
+
+const PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        year: 2016,
+        day: 1,
+        run: run::<aoc_2016_day01::Day01>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 2,
+        run: run::<aoc_2016_day02::Day02>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 3,
+        run: run::<aoc_2016_day03::Day03>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 4,
+        run: run::<aoc_2016_day04::Day04>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 5,
+        run: run::<aoc_2016_day05::Day05>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 6,
+        run: run::<aoc_2016_day06::Day06>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 7,
+        run: run::<aoc_2016_day07::Day07>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 8,
+        run: run::<aoc_2016_day08::Day08>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 9,
+        run: run::<aoc_2016_day09::Day09>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 10,
+        run: run::<aoc_2016_day10::Day10>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 11,
+        run: run::<aoc_2016_day11::Day11>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 12,
+        run: run::<aoc_2016_day12::Day12>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 13,
+        run: run::<aoc_2016_day13::Day13>,
+    },
+    Puzzle {
+        year: 2016,
+        day: 14,
+        run: run::<aoc_2016_day14::Day14>,
+    },
+    Puzzle {
+        year: 2017,
+        day: 1,
+        run: run::<aoc_2017_day01::Day01>,
+    },
+    Puzzle {
+        year: 2017,
+        day: 2,
+        run: run::<aoc_2017_day02::Day02>,
+    },
+    Puzzle {
+        year: 2017,
+        day: 3,
+        run: run::<aoc_2017_day03::Day03>,
+    },
+];
+
+fn default_input_path(year: u32, day: u32) -> PathBuf {
+    [
+        env!("CARGO_MANIFEST_DIR"),
+        "..",
+        &year.to_string(),
+        &format!("day{:02}", day),
+        "input.txt",
+    ]
+    .iter()
+    .collect()
+}
+
+fn load_input(puzzle: &Puzzle, input: Option<&PathBuf>) -> Result<String, String> {
+    let path = match input {
+        Some(path) => path.clone(),
+        None => default_input_path(puzzle.year, puzzle.day),
+    };
+    std::fs::read_to_string(&path)
+        .map_err(|err| format!("Unable to read input {}: {}", path.display(), err))
+}
+
+fn print_answer(part: u8, answer: &str) {
+    // Multi-line answers (e.g. 2016 day 8's screen) need to start on their
+    // own line in order to line up.
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+fn run<S: Solution>(
+    input: &str,
+    part: Option<u8>,
+    report: &mut dyn FnMut(Progress, Duration),
+) -> Result<(), ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    report(Progress::Parsed, start.elapsed());
+    if part != Some(2) {
+        let start = Instant::now();
+        let answer = S::part1(&parsed).to_string();
+        report(Progress::Solved(1, answer), start.elapsed());
+    }
+    if part != Some(1) {
+        let start = Instant::now();
+        let answer = S::part2(&parsed).to_string();
+        report(Progress::Solved(2, answer), start.elapsed());
+    }
+    Ok(())
+}
+
+fn find_puzzle(year: u32, day: u32) -> Result<&'static Puzzle, String> {
+    PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+        .ok_or_else(|| format!("No solution for {} day {}", year, day))
+}
+
+// The puzzles matching the (optional) year and day filters.
+fn select_puzzles(year: Option<u32>, day: Option<u32>) -> impl Iterator<Item = &'static Puzzle> {
+    PUZZLES.iter().filter(move |puzzle| {
+        year.is_none_or(|year| year == puzzle.year) && day.is_none_or(|day| day == puzzle.day)
+    })
+}
+
+// How `aoc run` should run and report a single puzzle.
+struct RunOptions {
+    time: bool,
+    format: OutputFormat,
+    timeout: Option<Duration>,
+    // How long to show each frame for, if the solution should be animated.
+    frame_delay: Option<Duration>,
+}
+
+fn run_puzzle(
+    year: u32,
+    day: u32,
+    part: Option<u8>,
+    input: Option<&PathBuf>,
+    options: &RunOptions,
+) -> Result<(), String> {
+    let puzzle = find_puzzle(year, day)?;
+    let input = load_input(puzzle, input)?;
+    let RunOptions { time, format, .. } = *options;
+    let token = options
+        .timeout
+        .map_or_else(CancelToken::new, CancelToken::with_timeout);
+    let mut parse_time = Duration::default();
+    let mut solve = || {
+        (puzzle.run)(&input, part, &mut |progress, elapsed| match (progress, format) {
+            (Progress::Parsed, _) => {
+                parse_time = elapsed;
+                if time && format == OutputFormat::Text {
+                    println!("Parsed in {:.2?}", elapsed);
+                }
+            }
+            (Progress::Solved(part, answer), OutputFormat::Text) => {
+                print_answer(part, &answer);
+                if time {
+                    println!("Part {} took {:.2?}", part, elapsed);
+                }
+            }
+            (Progress::Solved(part, answer), OutputFormat::Json) => {
+                println!("{}", AnswerRecord::new(year, day, part, &answer, parse_time, elapsed).to_json());
+            }
+        })
+    };
+    let result = cancel::run(&token, || match options.frame_delay {
+        Some(delay) => visualize::animate(delay, solve),
+        None => solve(),
+    });
+    match result {
+        Ok(result) => result.map_err(|err| format!("Unable to parse input for {} day {}: {}", year, day, err)),
+        Err(Cancelled) => Err(format!("{} day {} timed out", year, day)),
+    }
+}
+
+// Multi-line answers are compared without trailing whitespace, which tends
+// to get lost when editing the answers file.
+fn answers_match(expected: &str, actual: &str) -> bool {
+    let expected_lines = expected.trim_end().lines().map(str::trim_end);
+    let actual_lines = actual.trim_end().lines().map(str::trim_end);
+    expected_lines.eq(actual_lines)
+}
+
+#[derive(Default)]
+struct VerifySummary {
+    passed: usize,
+    mismatched: usize,
+    failed: usize,
+    unrecorded: usize,
+    skipped: usize,
+}
+
+// Runs the parts of a single puzzle that have recorded answers, and reports
+// how each of them compares.
+fn verify_puzzle(puzzle: &Puzzle, answers: &Answers, summary: &mut VerifySummary) {
+    let label = format!("{} day {:02}", puzzle.year, puzzle.day);
+    let expected: Vec<Option<&str>> = (1..=2)
+        .map(|part| answers.get(puzzle.year, puzzle.day, part))
+        .collect();
+    check_answers(puzzle, &label, load_input(puzzle, None), &expected, summary);
+    for (idx, answer) in expected.iter().enumerate() {
+        if answer.is_none() {
+            println!("{} part {}: no answer recorded", label, idx + 1);
+            summary.unrecorded += 1;
+        }
+    }
+}
+
+// The message that a solution panicked with, if it's a string (which it is
+// for `panic!`, `unwrap` and friends).
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|msg| msg.to_string()))
+        .unwrap_or_else(|| String::from("unknown error"))
+}
+
+fn check_answers(
+    puzzle: &Puzzle,
+    label: &str,
+    input: Result<String, String>,
+    expected: &[Option<&str>],
+    summary: &mut VerifySummary,
+) {
+    let part = match (expected[0], expected[1]) {
+        (None, None) => return,
+        (Some(_), None) => Some(1),
+        (None, Some(_)) => Some(2),
+        (Some(_), Some(_)) => None,
+    };
+    let num_parts = if part.is_some() { 1 } else { 2 };
+
+    let input = match input {
+        Ok(input) => input,
+        Err(err) => {
+            println!("{}: FAIL ({})", label, err);
+            summary.failed += num_parts;
+            return;
+        }
+    };
+
+    let mut num_reported = 0;
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        (puzzle.run)(&input, part, &mut |progress, _| {
+            let (part, answer) = match progress {
+                Progress::Parsed => return,
+                Progress::Solved(part, answer) => (part, answer),
+            };
+            num_reported += 1;
+            let expected = expected[part as usize - 1].unwrap();
+            if answers_match(expected, &answer) {
+                println!("{} part {}: pass", label, part);
+                summary.passed += 1;
+            } else {
+                println!("{} part {}: MISMATCH", label, part);
+                println!("  expected: {}", expected);
+                println!("  actual:   {}", answer);
+                summary.mismatched += 1;
+            }
+        })
+    }));
+    let error = match result {
+        Ok(Ok(())) => return,
+        Ok(Err(err)) => format!("unable to parse input: {}", err),
+        Err(payload) => format!("panicked: {}", panic_message(payload)),
+    };
+    println!("{}: FAIL ({})", label, error);
+    summary.failed += num_parts - num_reported;
+}
+
+fn verify(year: Option<u32>, day: Option<u32>, answers: Option<&PathBuf>) -> Result<(), String> {
+    let answers = Answers::load(&answers.cloned().unwrap_or_else(answers::default_path))?;
+    let mut summary = VerifySummary::default();
+    for puzzle in select_puzzles(year, day) {
+        verify_puzzle(puzzle, &answers, &mut summary);
+    }
+    print_summary(&summary)
+}
+
+// Checks each of the examples for a single puzzle, skipping the slow ones
+// unless asked to include them.
+fn verify_puzzle_examples(puzzle: &Puzzle, slow: bool, summary: &mut VerifySummary) {
+    let label = format!("{} day {:02}", puzzle.year, puzzle.day);
+    let examples = match fixtures::load(&fixtures::fixtures_dir(puzzle.year, puzzle.day)) {
+        Ok(examples) => examples,
+        Err(err) => {
+            println!("{}: FAIL ({})", label, err);
+            summary.failed += 1;
+            return;
+        }
+    };
+    for example in examples {
+        let label = format!("{} example {}", label, example.name);
+        let expected = [example.part1.as_deref(), example.part2.as_deref()];
+        if expected.iter().all(Option::is_none) {
+            println!("{}: no answers recorded", label);
+            summary.unrecorded += expected.len();
+            continue;
+        }
+        if example.slow && !slow {
+            println!("{}: skipped (slow)", label);
+            summary.skipped += expected.iter().flatten().count();
+            continue;
+        }
+        let input = std::fs::read_to_string(&example.input_path)
+            .map_err(|err| format!("Unable to read input {}: {}", example.input_path.display(), err));
+        check_answers(puzzle, &label, input, &expected, summary);
+    }
+}
+
+fn verify_examples(year: Option<u32>, day: Option<u32>, slow: bool) -> Result<(), String> {
+    let mut summary = VerifySummary::default();
+    for puzzle in select_puzzles(year, day) {
+        verify_puzzle_examples(puzzle, slow, &mut summary);
+    }
+    print_summary(&summary)
+}
+
+fn print_summary(summary: &VerifySummary) -> Result<(), String> {
+    println!();
+    println!(
+        "{} passed, {} mismatched, {} failed, {} with no recorded answer",
+        summary.passed, summary.mismatched, summary.failed, summary.unrecorded
+    );
+    if summary.skipped > 0 {
+        println!("{} slow example answers skipped; use --slow to check them", summary.skipped);
+    }
+    if summary.mismatched + summary.failed > 0 {
+        return Err(String::from("Verification failed"));
+    }
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    logging::init(cli.log);
+    let result = match &cli.command {
+        Command::Run {
+            year: Some(year),
+            day: Some(day),
+            part,
+            input,
+            time,
+            format,
+            timeout,
+            visualize,
+            frame_delay,
+            ..
+        } => {
+            let options = RunOptions {
+                time: *time,
+                format: *format,
+                timeout: timeout.map(Duration::from_secs),
+                frame_delay: if *visualize {
+                    Some(Duration::from_millis(*frame_delay))
+                } else {
+                    None
+                },
+            };
+            run_puzzle(*year, *day, *part, input.as_ref(), &options)
+        }
+        Command::Run {
+            part,
+            format,
+            only_year,
+            jobs,
+            timeout,
+            ..
+        } => {
+            let jobs = match jobs {
+                Some(jobs) => *jobs as usize,
+                None => thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+            };
+            let options = run_all::Options {
+                part: *part,
+                jobs,
+                timeout: Duration::from_secs(timeout.unwrap_or(60)),
+                format: *format,
+            };
+            run_all::run_all(select_puzzles(*only_year, None), &options)
+        }
+        Command::Verify { year, day, examples: true, slow, .. } => verify_examples(*year, *day, *slow),
+        Command::Verify { year, day, answers, .. } => verify(*year, *day, answers.as_ref()),
+        Command::New { year, day } => scaffold::new_day(*year, *day),
+        Command::Fetch {
+            year,
+            day,
+            base_url,
+            session,
+            cache_dir,
+        } => {
+            let config = fetch::FetchConfig::new(base_url.clone(), session.clone(), cache_dir.clone());
+            fetch::fetch(&config, *year, *day)
+        }
+        Command::Examples { year, day, page, dry_run } => extract::extract(*year, *day, page, *dry_run),
+        Command::Bench { year, day, runs, output } => {
+            let output = output.clone().unwrap_or_else(bench::default_output_path);
+            bench::bench(select_puzzles(*year, *day), *runs as usize, &output)
+        }
+    };
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,107 @@
+//! How `aoc run` reports its answers.
+//!
+//! The default text format is meant for people. With `--format json`, each
+//! answer is written as a single line of JSON as soon as it's known, so that
+//! scripts can consume the results without scraping:
+//!
+//! ```json
+//! {"year":2016,"day":2,"part":1,"answer":"24862","parse_ns":18230,"solve_ns":41870}
+//! ```
+//!
+//! Answers are always strings, since some of them aren't numbers (or don't
+//! fit in one), and multi-line answers keep their newlines.
+
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(Ok(OutputFormat::Json), "JSON".parse());
+        assert!("yaml".parse::<OutputFormat>().is_err());
+        for format in OutputFormat::ALL.iter() {
+            assert_eq!(Ok(*format), format.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn test_answer_json() {
+        let parse_time = Duration::from_micros(3);
+        let record = AnswerRecord::new(2016, 8, 2, "#..\n.#.", parse_time, Duration::from_millis(2));
+        let json: serde_json::Value = serde_json::from_str(&record.to_json()).unwrap();
+        assert_eq!(2016, json["year"]);
+        assert_eq!(8, json["day"]);
+        assert_eq!(2, json["part"]);
+        assert_eq!("#..\n.#.", json["answer"]);
+        assert_eq!(3_000, json["parse_ns"]);
+        assert_eq!(2_000_000, json["solve_ns"]);
+        assert!(!record.to_json().contains('\n'));
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    pub const ALL: [OutputFormat; 2] = [OutputFormat::Text, OutputFormat::Json];
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<OutputFormat, String> {
+        OutputFormat::ALL
+            .iter()
+            .find(|format| format.to_string().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("expected text or json but got {:?}", s))
+    }
+}
+
+/// A single answer, along with how long it took to get it.
+#[derive(Debug, Serialize)]
+pub struct AnswerRecord {
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: String,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+impl AnswerRecord {
+    pub fn new(year: u32, day: u32, part: u8, answer: &str, parse_time: Duration, solve_time: Duration) -> AnswerRecord {
+        AnswerRecord {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            parse_ns: parse_time.as_nanos() as u64,
+            solve_ns: solve_time.as_nanos() as u64,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("answers are always serializable")
+    }
+}
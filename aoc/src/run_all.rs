@@ -0,0 +1,350 @@
+//! `aoc run --all` (or `--year`): runs many puzzles at once and prints a
+//! table of their answers, how long they took, and anything that went wrong.
+//!
+//! Each puzzle runs on its own thread, with at most `--jobs` of them running
+//! at a time. A puzzle that goes over the time limit is reported as timed
+//! out, and its cancellation token is cancelled so that it stops at its next
+//! `cancel::check`. A thread can't be stopped from the outside, so solutions
+//! that never check are left to finish in the background; either way, it no
+//! longer counts towards the number of jobs, so the rest of the puzzles
+//! aren't held up waiting for it (even though it's still using a CPU).
+
+use crate::output::{AnswerRecord, OutputFormat};
+use crate::{load_input, panic_message, Progress, Puzzle, RunFn};
+use aoc_common::cancel::{self, CancelToken, Cancelled};
+use std::cell::Cell;
+use std::panic;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseError;
+    use pretty_assertions::assert_eq;
+
+    fn echo(input: &str, _: Option<u8>, report: &mut dyn FnMut(Progress, Duration)) -> Result<(), ParseError> {
+        report(Progress::Parsed, Duration::from_millis(1));
+        report(Progress::Solved(1, input.to_string()), Duration::from_millis(2));
+        Ok(())
+    }
+
+    fn slow(_: &str, _: Option<u8>, report: &mut dyn FnMut(Progress, Duration)) -> Result<(), ParseError> {
+        report(Progress::Solved(1, String::from("fast")), Duration::from_millis(2));
+        loop {
+            cancel::check();
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    fn broken(_: &str, _: Option<u8>, _: &mut dyn FnMut(Progress, Duration)) -> Result<(), ParseError> {
+        panic!("oops");
+    }
+
+    static ECHO: Puzzle = Puzzle { year: 2016, day: 1, run: echo };
+    static SLOW: Puzzle = Puzzle { year: 2016, day: 2, run: slow };
+    static BROKEN: Puzzle = Puzzle { year: 2016, day: 3, run: broken };
+
+    #[test]
+    fn test_run_parallel() {
+        let inputs = vec![
+            (&SLOW, Ok(String::new())),
+            (&BROKEN, Ok(String::new())),
+            (&ECHO, Err(String::from("no input"))),
+            (&ECHO, Ok(String::from("hello"))),
+        ];
+        let options = Options {
+            part: None,
+            jobs: 2,
+            timeout: Duration::from_millis(200),
+            format: OutputFormat::Text,
+        };
+        let outcomes = run_parallel(inputs, &options);
+
+        // The slow puzzle keeps its first answer, and doesn't stop the
+        // others from running.
+        assert_eq!(Status::TimedOut, outcomes[0].status);
+        assert_eq!(Some("fast"), outcomes[0].answer(1));
+        assert_eq!(None, outcomes[0].answer(2));
+        assert_eq!(Status::Failed(String::from("panicked: oops")), outcomes[1].status);
+        assert_eq!(Status::Skipped(String::from("no input")), outcomes[2].status);
+        assert_eq!(Status::Finished, outcomes[3].status);
+        assert_eq!(Some("hello"), outcomes[3].answer(1));
+        assert_eq!(Some(Duration::from_millis(1)), outcomes[3].parse_time);
+    }
+
+    #[test]
+    fn test_answer_cell() {
+        assert_eq!("-", answer_cell(None));
+        assert_eq!("46C91", answer_cell(Some("46C91")));
+        assert_eq!("(2 lines)", answer_cell(Some("#.\n.#")));
+    }
+}
+
+thread_local! {
+    // Set on the threads that run the puzzles.
+    static IS_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+pub struct Options {
+    pub part: Option<u8>,
+    pub jobs: usize,
+    pub timeout: Duration,
+    pub format: OutputFormat,
+}
+
+// What a puzzle's thread reports back while it runs.
+enum Event {
+    Progress(Progress, Duration),
+    Finished(Result<(), String>),
+}
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Running,
+    Finished,
+    // The puzzle couldn't be run at all, e.g. because there's no input.
+    Skipped(String),
+    Failed(String),
+    TimedOut,
+}
+
+impl Status {
+    fn is_failure(&self) -> bool {
+        matches!(self, Status::Failed(_) | Status::TimedOut)
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Status::Running => String::from("running"),
+            Status::Finished => String::from("ok"),
+            Status::Skipped(reason) => format!("skipped ({})", reason),
+            Status::Failed(err) => format!("FAIL ({})", err),
+            Status::TimedOut => String::from("TIMED OUT"),
+        }
+    }
+}
+
+struct Outcome {
+    puzzle: &'static Puzzle,
+    parse_time: Option<Duration>,
+    answers: Vec<(u8, String, Duration)>,
+    status: Status,
+    elapsed: Duration,
+}
+
+impl Outcome {
+    fn new(puzzle: &'static Puzzle) -> Outcome {
+        Outcome {
+            puzzle,
+            parse_time: None,
+            answers: Vec::new(),
+            status: Status::Running,
+            elapsed: Duration::default(),
+        }
+    }
+
+    fn answer(&self, part: u8) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|(answer_part, _, _)| *answer_part == part)
+            .map(|(_, answer, _)| answer.as_str())
+    }
+
+    fn record(&mut self, event: Event, started: Instant) {
+        match event {
+            Event::Progress(Progress::Parsed, elapsed) => self.parse_time = Some(elapsed),
+            Event::Progress(Progress::Solved(part, answer), elapsed) => self.answers.push((part, answer, elapsed)),
+            Event::Finished(result) => {
+                self.elapsed = started.elapsed();
+                self.status = match result {
+                    Ok(()) => Status::Finished,
+                    Err(err) => Status::Failed(err),
+                };
+            }
+        }
+    }
+}
+
+fn spawn(
+    idx: usize,
+    run: RunFn,
+    input: String,
+    part: Option<u8>,
+    token: CancelToken,
+    sender: mpsc::Sender<(usize, Event)>,
+) {
+    thread::spawn(move || {
+        IS_WORKER.with(|is_worker| is_worker.set(true));
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            cancel::run(&token, || {
+                run(&input, part, &mut |progress, elapsed| {
+                    // The receiver only goes away once every puzzle has
+                    // finished or timed out, and then nobody's interested
+                    // any more.
+                    let _ = sender.send((idx, Event::Progress(progress, elapsed)));
+                })
+            })
+        }));
+        let result = match result {
+            Ok(Ok(Ok(()))) => Ok(()),
+            Ok(Ok(Err(err))) => Err(format!("unable to parse input: {}", err)),
+            // Only happens after the scheduler has given up on the puzzle.
+            Ok(Err(Cancelled)) => Err(String::from("cancelled")),
+            Err(payload) => Err(format!("panicked: {}", panic_message(payload))),
+        };
+        let _ = sender.send((idx, Event::Finished(result)));
+    });
+}
+
+// Runs each puzzle on its input, returning how each of them went in the same
+// order as the inputs.
+fn run_parallel(inputs: Vec<(&'static Puzzle, Result<String, String>)>, options: &Options) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+    let mut queue = Vec::new();
+    for (idx, (puzzle, input)) in inputs.into_iter().enumerate() {
+        let mut outcome = Outcome::new(puzzle);
+        match input {
+            Ok(input) => queue.push((idx, input)),
+            Err(err) => outcome.status = Status::Skipped(err),
+        }
+        outcomes.push(outcome);
+    }
+    // Popping from the back, so start them in order.
+    queue.reverse();
+
+    let (sender, receiver) = mpsc::channel();
+    let mut running: Vec<(usize, Instant, CancelToken)> = Vec::new();
+    loop {
+        while running.len() < options.jobs {
+            let Some((idx, input)) = queue.pop() else {
+                break;
+            };
+            let token = CancelToken::new();
+            spawn(idx, outcomes[idx].puzzle.run, input, options.part, token.clone(), sender.clone());
+            running.push((idx, Instant::now(), token));
+        }
+        let Some(deadline) = running.iter().map(|(_, started, _)| *started + options.timeout).min() else {
+            break;
+        };
+
+        // Since we're holding on to a sender, this can only fail by timing
+        // out, which is handled below.
+        if let Ok((idx, event)) = receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            // Puzzles that have already timed out might still be sending
+            // answers, which are too late to count.
+            if let Some(pos) = running.iter().position(|(running_idx, _, _)| *running_idx == idx) {
+                outcomes[idx].record(event, running[pos].1);
+                if outcomes[idx].status != Status::Running {
+                    running.swap_remove(pos);
+                }
+            }
+        }
+
+        let now = Instant::now();
+        running.retain(|(idx, started, token)| {
+            if now.duration_since(*started) < options.timeout {
+                return true;
+            }
+            token.cancel();
+            log::info!("{} day {:02} timed out", outcomes[*idx].puzzle.year, outcomes[*idx].puzzle.day);
+            outcomes[*idx].status = Status::TimedOut;
+            outcomes[*idx].elapsed = options.timeout;
+            false
+        });
+    }
+    outcomes
+}
+
+// Multi-line answers (2016 day 8's screen) don't fit in a table, so they're
+// just summarised; `aoc run <year> <day>` will show them in full.
+fn answer_cell(answer: Option<&str>) -> String {
+    match answer {
+        None => String::from("-"),
+        Some(answer) if answer.contains('\n') => format!("({} lines)", answer.lines().count()),
+        Some(answer) => answer.to_string(),
+    }
+}
+
+fn print_table(outcomes: &[Outcome]) {
+    let rows: Vec<[String; 5]> = outcomes
+        .iter()
+        .map(|outcome| {
+            let elapsed = match outcome.status {
+                Status::Skipped(_) => String::from("-"),
+                _ => format!("{:.2?}", outcome.elapsed),
+            };
+            [
+                format!("{} day {:02}", outcome.puzzle.year, outcome.puzzle.day),
+                answer_cell(outcome.answer(1)),
+                answer_cell(outcome.answer(2)),
+                elapsed,
+                outcome.status.describe(),
+            ]
+        })
+        .collect();
+    let header = ["Puzzle", "Part 1", "Part 2", "Time", "Status"].map(String::from);
+    let mut widths = [0; 4];
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        println!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+}
+
+fn print_json(outcomes: &[Outcome]) {
+    for outcome in outcomes {
+        let Puzzle { year, day, .. } = *outcome.puzzle;
+        let parse_time = outcome.parse_time.unwrap_or_default();
+        for (part, answer, elapsed) in &outcome.answers {
+            println!("{}", AnswerRecord::new(year, day, *part, answer, parse_time, *elapsed).to_json());
+        }
+        // Keep stdout as nothing but answers.
+        if outcome.status != Status::Finished {
+            eprintln!("{} day {:02}: {}", year, day, outcome.status.describe());
+        }
+    }
+}
+
+pub fn run_all(puzzles: impl Iterator<Item = &'static Puzzle>, options: &Options) -> Result<(), String> {
+    let inputs = puzzles
+        .map(|puzzle| (puzzle, load_input(puzzle, None)))
+        .collect();
+    // A puzzle's panic is reported in its status, so the usual message
+    // would only get in the way of the table. Panics anywhere else are
+    // still passed on.
+    let previous_hook: Arc<dyn Fn(&panic::PanicHookInfo) + Send + Sync> = Arc::from(panic::take_hook());
+    let hook = Arc::clone(&previous_hook);
+    panic::set_hook(Box::new(move |info| {
+        if !IS_WORKER.with(Cell::get) {
+            hook(info);
+        }
+    }));
+    let outcomes = run_parallel(inputs, options);
+    match options.format {
+        OutputFormat::Text => print_table(&outcomes),
+        OutputFormat::Json => print_json(&outcomes),
+    }
+    panic::set_hook(Box::new(move |info| previous_hook(info)));
+
+    let failures = outcomes.iter().filter(|outcome| outcome.status.is_failure()).count();
+    if failures > 0 {
+        return Err(format!("{} of {} puzzles failed or timed out", failures, outcomes.len()));
+    }
+    Ok(())
+}
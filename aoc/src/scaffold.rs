@@ -0,0 +1,352 @@
+//! `aoc new <year> <day>`: sets up the crate for a new day, following the
+//! same layout as the existing ones, and registers it with the workspace,
+//! the runner and the answers registry.
+
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const MAIN_RS: &str = "const PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        year: 2016,
+        day: 1,
+        run: run::<aoc_2016_day01::Day01>,
+    },
+    Puzzle {
+        year: 2017,
+        day: 1,
+        run: run::<aoc_2017_day01::Day01>,
+    },
+];
+";
+
+    #[test]
+    fn test_add_puzzle_entry() {
+        let main_rs = add_puzzle_entry(MAIN_RS, 2016, 2).unwrap();
+        let day02 = main_rs.find("aoc_2016_day02::Day02").unwrap();
+        assert!(main_rs.find("aoc_2016_day01").unwrap() < day02);
+        assert!(day02 < main_rs.find("aoc_2017_day01").unwrap());
+
+        let main_rs = add_puzzle_entry(MAIN_RS, 2018, 25).unwrap();
+        assert!(main_rs.ends_with(
+            "    Puzzle {
+        year: 2018,
+        day: 25,
+        run: run::<aoc_2018_day25::Day25>,
+    },
+];
+"
+        ));
+        assert!(add_puzzle_entry(MAIN_RS, 2016, 1).is_err());
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let cargo_toml = "[dependencies]
+clap = \"4.5\"
+aoc-2016-day01 = { path = \"../2016/day01\" }
+aoc-2017-day01 = { path = \"../2017/day01\" }
+
+[dev-dependencies]
+";
+        assert_eq!(
+            "[dependencies]
+clap = \"4.5\"
+aoc-2016-day01 = { path = \"../2016/day01\" }
+aoc-2016-day09 = { path = \"../2016/day09\" }
+aoc-2017-day01 = { path = \"../2017/day01\" }
+
+[dev-dependencies]
+",
+            add_dependency(cargo_toml, 2016, 9).unwrap()
+        );
+        assert!(add_dependency(cargo_toml, 2017, 1).is_err());
+    }
+
+    #[test]
+    fn test_add_answers_entry() {
+        let answers = "# Header\n\n[2016.day01]\npart1 = \"1\"\n\n[2017.day01]\npart1 = \"2\"\n";
+        assert_eq!(
+            "# Header\n\n[2016.day01]\npart1 = \"1\"\n\n[2016.day02]\n\n[2017.day01]\npart1 = \"2\"\n",
+            add_answers_entry(answers, 2016, 2).unwrap()
+        );
+        assert_eq!(
+            "# Header\n\n[2016.day01]\npart1 = \"1\"\n\n[2017.day01]\npart1 = \"2\"\n\n[2018.day01]\n",
+            add_answers_entry(answers, 2018, 1).unwrap()
+        );
+        assert!(add_answers_entry(answers, 2016, 1).is_err());
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"2016/day*\",\n]\n";
+        assert_eq!(manifest, add_workspace_member(manifest, 2016).unwrap());
+        assert_eq!(
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2016/day*\",\n    \"2018/day*\",\n]\n",
+            add_workspace_member(manifest, 2018).unwrap()
+        );
+    }
+}
+
+pub fn repo_root() -> PathBuf {
+    let runner_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    runner_dir.parent().unwrap_or(runner_dir).to_path_buf()
+}
+
+fn crate_name(year: u32, day: u32) -> String {
+    format!("aoc-{}-day{:02}", year, day)
+}
+
+fn cargo_toml(year: u32, day: u32) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+authors = ["Laura Lindzey <lindzey@gmail.com>"]
+edition = "2018"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-common = {{ path = "../../common" }}
+log = "0.4"
+
+[dev-dependencies]
+pretty_assertions = "0.6.1"
+
+[lints]
+workspace = true
+"#,
+        crate_name(year, day)
+    )
+}
+
+fn lib_rs(day: u32) -> String {
+    format!(
+        r#"use aoc_common::{{ParseError, Solution, Unsolved}};
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() {{
+        assert_eq!(Ok(String::from("input")), Day{day:02}::parse("input\n"));
+    }}
+}}
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = String;
+    type Answer1 = Unsolved;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(input.trim().to_string())
+    }}
+
+    fn part1(_input: &Self::Input) -> Unsolved {{
+        Unsolved
+    }}
+
+    fn part2(_input: &Self::Input) -> Unsolved {{
+        Unsolved
+    }}
+}}
+"#,
+        day = day
+    )
+}
+
+fn main_rs(year: u32, day: u32) -> String {
+    format!(
+        r#"use aoc_{year}_day{day:02}::Day{day:02};
+use aoc_common::{{ParseError, Solution}};
+
+fn main() -> Result<(), ParseError> {{
+    let input = std::fs::read_to_string("input.txt").expect("Unable to read input.txt");
+    let input = Day{day:02}::parse(&input)?;
+    let answer1 = Day{day:02}::part1(&input);
+    println!("Part 1: {{}}", answer1);
+    let answer2 = Day{day:02}::part2(&input);
+    println!("Part 2: {{}}", answer2);
+    Ok(())
+}}
+"#,
+        year = year,
+        day = day
+    )
+}
+
+const FIXTURE_ANSWERS: &str = "# Expected answers for the examples in this directory; see aoc/src/fixtures.rs.
+
+[example]
+";
+
+// Adds the day to the runner's table of puzzles, keeping it in order.
+fn add_puzzle_entry(main_rs: &str, year: u32, day: u32) -> Result<String, String> {
+    let table_start = main_rs
+        .find("const PUZZLES: &[Puzzle] = &[\n")
+        .ok_or("Unable to find the table of puzzles")?;
+    let table_end = table_start
+        + main_rs[table_start..]
+            .find("\n];\n")
+            .ok_or("Unable to find the end of the table of puzzles")?
+        + 1;
+
+    // Each entry starts with its year and day, which is enough to find
+    // where the new one belongs.
+    let mut insert_at = table_end;
+    for (offset, _) in main_rs[table_start..table_end].match_indices("    Puzzle {\n") {
+        let entry_start = table_start + offset;
+        let entry = &main_rs[entry_start..table_end];
+        let key = |field: &str| -> Option<u32> {
+            let start = entry.find(field)? + field.len();
+            let end = start + entry[start..].find(',')?;
+            entry[start..end].parse().ok()
+        };
+        let entry_key = (key("year: "), key("day: "));
+        if entry_key == (Some(year), Some(day)) {
+            return Err(format!("{} day {} is already in the runner", year, day));
+        }
+        if entry_key > (Some(year), Some(day)) {
+            insert_at = entry_start;
+            break;
+        }
+    }
+    let entry = format!(
+        "    Puzzle {{\n        year: {year},\n        day: {day},\n        run: run::<aoc_{year}_day{day:02}::Day{day:02}>,\n    }},\n",
+        year = year,
+        day = day
+    );
+    Ok(format!("{}{}{}", &main_rs[..insert_at], entry, &main_rs[insert_at..]))
+}
+
+// Adds the day's crate to the runner's dependencies, after the last day
+// that comes before it.
+fn add_dependency(cargo_toml: &str, year: u32, day: u32) -> Result<String, String> {
+    let name = crate_name(year, day);
+    let mut lines: Vec<&str> = cargo_toml.lines().collect();
+    let dependency_name = |line: &str| line.split(' ').next().unwrap_or("").to_string();
+    if lines.iter().any(|line| dependency_name(line) == name) {
+        return Err(format!("{} is already a dependency of the runner", name));
+    }
+    let last_before = lines
+        .iter()
+        .rposition(|line| line.starts_with("aoc-") && dependency_name(line) < name);
+    let insert_at = match last_before {
+        Some(idx) => idx + 1,
+        None => {
+            lines
+                .iter()
+                .position(|line| *line == "[dependencies]")
+                .ok_or("Unable to find the runner's dependencies")?
+                + 1
+        }
+    };
+    let dependency = format!("{} = {{ path = \"../{}/day{:02}\" }}", name, year, day);
+    lines.insert(insert_at, &dependency);
+    Ok(lines.join("\n") + "\n")
+}
+
+// Adds an empty table for the day to the answers registry, so that it's
+// ready to be filled in once the puzzle is solved.
+fn add_answers_entry(answers: &str, year: u32, day: u32) -> Result<String, String> {
+    let header = format!("[{}.day{:02}]", year, day);
+    if answers.lines().any(|line| line == header) {
+        return Err(format!("{} is already in the answers registry", header));
+    }
+    // Headers are all formatted the same way, so compare as strings.
+    let next = answers
+        .match_indices("\n[")
+        .map(|(idx, _)| idx + 1)
+        .find(|&idx| answers[idx..].lines().next().unwrap_or("") > header.as_str());
+    Ok(match next {
+        Some(idx) => format!("{}{}\n\n{}", &answers[..idx], header, &answers[idx..]),
+        None => format!("{}\n\n{}\n", answers.trim_end_matches('\n'), header),
+    })
+}
+
+// The workspace picks up every day of a year with a glob, so a new member
+// is only needed for the first day of a new year.
+fn add_workspace_member(manifest: &str, year: u32) -> Result<String, String> {
+    let member = format!("    \"{}/day*\",\n", year);
+    if manifest.contains(&member) {
+        return Ok(manifest.to_string());
+    }
+    let members_start = manifest
+        .find("members = [\n")
+        .ok_or("Unable to find the workspace members")?;
+    let members_end = members_start
+        + manifest[members_start..]
+            .find("]\n")
+            .ok_or("Unable to find the end of the workspace members")?;
+    Ok(format!("{}{}{}", &manifest[..members_end], member, &manifest[members_end..]))
+}
+
+// Reads a file and works out its new contents, without writing anything.
+fn updated(path: &Path, update: impl FnOnce(&str) -> Result<String, String>) -> Result<(PathBuf, String), String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+    let contents = update(&contents).map_err(|err| format!("Unable to update {}: {}", path.display(), err))?;
+    Ok((path.to_path_buf(), contents))
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| format!("Unable to create {}: {}", dir.display(), err))?;
+    }
+    std::fs::write(path, contents).map_err(|err| format!("Unable to write {}: {}", path.display(), err))
+}
+
+pub fn new_day(year: u32, day: u32) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("There is no day {}; puzzles run from day 1 to 25", day));
+    }
+    let root = repo_root();
+    let day_dir = root.join(year.to_string()).join(format!("day{:02}", day));
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    // Work out all of the registrations before touching anything, so that
+    // a failure doesn't leave a half-made day behind.
+    let updates = [
+        updated(&root.join("Cargo.toml"), |text| add_workspace_member(text, year))?,
+        updated(&root.join("aoc").join("Cargo.toml"), |text| add_dependency(text, year, day))?,
+        updated(&root.join("aoc").join("src").join("main.rs"), |text| {
+            add_puzzle_entry(text, year, day)
+        })?,
+        updated(&root.join("answers.toml"), |text| add_answers_entry(text, year, day))?,
+    ];
+
+    let files = [
+        (day_dir.join("Cargo.toml"), cargo_toml(year, day)),
+        (day_dir.join("src").join("lib.rs"), lib_rs(day)),
+        (day_dir.join("src").join("main.rs"), main_rs(year, day)),
+        (day_dir.join("fixtures").join("example.txt"), String::new()),
+        (day_dir.join("fixtures").join("answers.toml"), FIXTURE_ANSWERS.to_string()),
+    ];
+    for (path, contents) in files.iter() {
+        write_file(path, contents)?;
+        println!("Created {}", path.display());
+    }
+    for (path, contents) in updates.iter() {
+        write_file(path, contents)?;
+        println!("Updated {}", path.display());
+    }
+
+    println!();
+    println!(
+        "Save the puzzle input as {}, or download it with `aoc fetch {} {}`",
+        day_dir.join("input.txt").display(),
+        year,
+        day
+    );
+    Ok(())
+}
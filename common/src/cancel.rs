@@ -0,0 +1,136 @@
+//! Cooperative cancellation for long-running solutions.
+//!
+//! The runner installs a `CancelToken` (optionally with a deadline) on the
+//! thread that's running a solution, using `run`. Searches and hash loops
+//! that might not finish call `check` every so often; once the token has
+//! been cancelled or its deadline has passed, `check` unwinds out of the
+//! solution and `run` returns `Err(Cancelled)`. This keeps cancellation out
+//! of every solution's signature, in the same way that logging is.
+//!
+//! Without a token installed (e.g. in a day's own tests), `check` never
+//! does anything.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn spin_forever() -> u64 {
+        loop {
+            check();
+        }
+    }
+
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::new();
+        assert!(!token.is_cancelled());
+        let other = token.clone();
+        other.cancel();
+        assert!(token.is_cancelled());
+        assert_eq!(Err(Cancelled), run(&token, spin_forever));
+    }
+
+    #[test]
+    fn test_deadline() {
+        let token = CancelToken::with_timeout(Duration::from_millis(10));
+        assert_eq!(Err(Cancelled), run(&token, spin_forever));
+        assert_eq!(Ok(3), run(&CancelToken::new(), || 3));
+    }
+
+    #[test]
+    fn test_check_without_token() {
+        check();
+        assert!(!is_cancelled());
+        // The token is only installed for the duration of `run`.
+        let token = CancelToken::new();
+        token.cancel();
+        let _ = run(&token, || ());
+        check();
+    }
+
+    #[test]
+    #[should_panic(expected = "oops")]
+    fn test_other_panics() {
+        let _ = run(&CancelToken::new(), || panic!("oops"));
+    }
+}
+
+/// Shared flag for asking a solution to give up, with an optional deadline
+/// after which it counts as cancelled anyway. Clones share the same flag.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// A token that cancels itself once `timeout` has passed.
+    pub fn with_timeout(timeout: Duration) -> CancelToken {
+        CancelToken {
+            cancelled: Arc::default(),
+            deadline: Some(Instant::now() + timeout),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// Returned by `run` when the solution was cancelled before it finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` installed for this thread, so that any `check` it
+/// makes can cancel it. Other panics are passed through unchanged.
+pub fn run<R>(token: &CancelToken, f: impl FnOnce() -> R) -> Result<R, Cancelled> {
+    let previous = CURRENT.with(|current| current.replace(Some(token.clone())));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    CURRENT.with(|current| current.replace(previous));
+    match result {
+        Ok(result) => Ok(result),
+        Err(payload) if payload.is::<Cancelled>() => Err(Cancelled),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Whether the current thread's solution has been asked to give up.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(CancelToken::is_cancelled))
+}
+
+/// Stops the current solution if it's been cancelled. Cheap enough to call
+/// once per iteration of a search or hash loop.
+pub fn check() {
+    if is_cancelled() {
+        // Unlike `panic!`, this doesn't run the panic hook, so nothing gets
+        // printed on the way out.
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
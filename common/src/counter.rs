@@ -0,0 +1,110 @@
+//! Counting how often each item occurs, e.g. the letters in a string.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_counts() {
+        let counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(5, counter.get(&'a'));
+        assert_eq!(0, counter.get(&'z'));
+        assert_eq!(5, counter.len());
+        assert_eq!(vec![(&'a', 5), (&'b', 2), (&'r', 2)], counter.most_common(3));
+        assert_eq!(vec![(&'c', 1), (&'d', 1)], counter.least_common(2));
+    }
+
+    #[test]
+    fn test_ties() {
+        // Ties always go to the smallest item, whichever order they were
+        // counted in.
+        let mut counter = Counter::new();
+        counter.extend("zyxwba".chars());
+        counter.add('z');
+        assert_eq!(vec![(&'z', 2), (&'a', 1), (&'b', 1)], counter.most_common(3));
+        assert_eq!(vec![(&'a', 1), (&'b', 1)], counter.least_common(2));
+        assert_eq!(6, counter.most_common(10).len());
+        assert_eq!(Vec::<(&char, usize)>::new(), Counter::<char>::new().most_common(1));
+    }
+}
+
+/// Number of times that each item has been seen.
+///
+/// `most_common` and `least_common` break ties between items with the same
+/// count by the items' ordering, smallest first, so that (unlike picking
+/// the max from a `HashMap`) the results don't depend on iteration order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Counter<T: Ord> {
+    counts: BTreeMap<T, usize>,
+}
+
+impl<T: Ord> Counter<T> {
+    pub fn new() -> Counter<T> {
+        Counter {
+            counts: BTreeMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, item: T) {
+        *self.counts.entry(item).or_insert(0) += 1;
+    }
+
+    /// How many times `item` has been seen; zero if it hasn't been.
+    pub fn get(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Number of distinct items.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Every item and its count, in the items' order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, count)| (item, *count))
+    }
+
+    /// The `k` items seen most often, most common first. Returns fewer if
+    /// there aren't `k` distinct items.
+    pub fn most_common(&self, k: usize) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.iter().collect();
+        // Stable, so equal counts stay in the items' order.
+        items.sort_by_key(|(_, count)| Reverse(*count));
+        items.truncate(k);
+        items
+    }
+
+    /// The `k` items seen least often, least common first. Returns fewer if
+    /// there aren't `k` distinct items.
+    pub fn least_common(&self, k: usize) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.iter().collect();
+        items.sort_by_key(|(_, count)| *count);
+        items.truncate(k);
+        items
+    }
+}
+
+impl<T: Ord> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Counter<T> {
+        let mut counter = Counter::new();
+        counter.extend(items);
+        counter
+    }
+}
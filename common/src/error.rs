@@ -0,0 +1,79 @@
+//! Errors for puzzle input that couldn't be parsed.
+
+use std::error::Error;
+use std::fmt;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_column() {
+        let line = "cpy 41 q";
+        let err = ParseError::new(line, &line[7..], "not a register");
+        assert_eq!(1, err.line);
+        assert_eq!(8, err.column);
+        assert_eq!("q", err.text);
+
+        // Text that isn't a slice of the line is found by searching for it.
+        let err = ParseError::new(line, "41", "too big");
+        assert_eq!(5, err.column);
+        let err = ParseError::new(line, "missing", "not found");
+        assert_eq!(1, err.column);
+    }
+}
+
+/// Describes where parsing went wrong: the 1-based line and column of the
+/// offending text, the text itself, and what was wrong with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error for `text`, which was found on `line`. The column is worked out
+    /// from where `text` sits in `line`, so passing a slice of the line is
+    /// the most accurate. The line number defaults to 1; use `on_line` (or
+    /// `input::parse_lines`) to set it for multi-line input.
+    pub fn new(line: &str, text: &str, message: impl fmt::Display) -> ParseError {
+        ParseError {
+            line: 1,
+            column: column_of(line, text),
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+}
+
+// 1-based column (in characters) of text within line.
+fn column_of(line: &str, text: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let text_start = text.as_ptr() as usize;
+    let byte_idx = if line_start <= text_start && text_start + text.len() <= line_start + line.len() {
+        text_start - line_start
+    } else {
+        line.find(text).unwrap_or(0)
+    };
+    line[..byte_idx].chars().count() + 1
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} ({:?})",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
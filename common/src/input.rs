@@ -0,0 +1,203 @@
+//! Splitting puzzle input up into records.
+//!
+//! Everything here accepts both `\n` and `\r\n` line endings and ignores
+//! blank lines at the end of the input, so a file saved by any editor (or
+//! downloaded with a trailing newline) parses the same way. Errors are
+//! tagged with the line that they came from.
+
+use crate::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(vec!["a", "b"], lines("a\nb"));
+        assert_eq!(vec!["a", "b"], lines("a\r\nb\r\n\r\n"));
+        assert_eq!(vec!["a", "", "b"], lines("a\n\nb\n  \n"));
+        assert_eq!(Vec::<&str>::new(), lines(""));
+        assert_eq!(Vec::<&str>::new(), lines("\n"));
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(Ok("abc"), single_line("abc"));
+        assert_eq!(Ok("abc"), single_line(" abc \r\n\n"));
+
+        let err = single_line(" \n").unwrap_err();
+        assert_eq!("expected a line of input", err.message);
+        let err = single_line("abc\ndef\n").unwrap_err();
+        assert_eq!((2, "def"), (err.line, err.text.as_str()));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| {
+            line.parse::<i32>()
+                .map_err(|err| ParseError::new(line, line, err))
+        };
+        assert_eq!(Ok(vec![1, 2, 3]), parse_lines("1\n2\n3\n", parse));
+        assert_eq!(Ok(vec![1, 2]), parse_lines("1\r\n2\r\n", parse));
+
+        let err = parse_lines("1\n2\nthree", parse).unwrap_err();
+        assert_eq!(3, err.line);
+        assert_eq!(1, err.column);
+        assert_eq!("three", err.text);
+        assert_eq!(
+            "line 3, column 1: invalid digit found in string (\"three\")",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\na\nb\n\n\nc\r\n \r\nd\n\n";
+        assert_eq!(vec![vec!["a", "b"], vec!["c"], vec!["d"]], blocks(input));
+
+        let count = |block: &[&str]| {
+            if block.len() > 1 {
+                Err(ParseError::new(block[1], block[1], "too long").on_line(2))
+            } else {
+                Ok(block.len())
+            }
+        };
+        assert_eq!(Ok(vec![1, 1]), parse_blocks("a\n\nb\n", count));
+        let err = parse_blocks("a\n\nb\nc\n", count).unwrap_err();
+        assert_eq!((4, "c"), (err.line, err.text.as_str()));
+    }
+
+    #[test]
+    fn test_number_table() {
+        let table: Vec<Vec<i32>> = number_table("5 1 9 5\n7\t5 3\r\n\n2 4 6 8\n").unwrap();
+        assert_eq!(vec![vec![5, 1, 9, 5], vec![7, 5, 3], vec![], vec![2, 4, 6, 8]], table);
+
+        let err = number_table::<u32>("1 2\n3 -4").unwrap_err();
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!("-4", err.text);
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid = char_grid("ab\r\ncd\n").unwrap();
+        assert_eq!(vec![vec!['a', 'b'], vec!['c', 'd']], grid);
+
+        let err = char_grid("abc\nab\n").unwrap_err();
+        assert_eq!(2, err.line);
+    }
+}
+
+/// The lines of the input, without line endings or trailing blank lines.
+pub fn lines(input: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// The input's only line, without any surrounding whitespace, for puzzles
+/// whose input is a single value.
+pub fn single_line(input: &str) -> Result<&str, ParseError> {
+    match lines(input)[..] {
+        [line] if !line.trim().is_empty() => Ok(line.trim()),
+        [] | [_] => Err(ParseError::new(input, input, "expected a line of input")),
+        [_, line, ..] => Err(ParseError::new(line, line, "expected only one line of input").on_line(2)),
+    }
+}
+
+/// Parses each line of the input in turn, stopping at the first error and
+/// tagging it with the line number that it came from.
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    lines(input)
+        .into_iter()
+        .enumerate()
+        .map(|(idx, line)| parse_line(line).map_err(|err| err.on_line(idx + 1)))
+        .collect()
+}
+
+// Groups of consecutive non-blank lines, along with the (0-based) index of
+// each group's first line.
+fn indexed_blocks(input: &str) -> Vec<(usize, Vec<&str>)> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    for (idx, line) in lines(input).into_iter().enumerate() {
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current.get_or_insert_with(|| (idx, Vec::new())).1.push(line);
+        }
+    }
+    blocks.extend(current);
+    blocks
+}
+
+/// Groups of lines separated by one or more blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<&str>> {
+    indexed_blocks(input)
+        .into_iter()
+        .map(|(_, block)| block)
+        .collect()
+}
+
+/// Parses each blank-line-separated block of the input in turn. Errors
+/// should use the line number within the block (see `ParseError::on_line`),
+/// which is converted into the line number within the whole input.
+pub fn parse_blocks<T, F>(input: &str, mut parse_block: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&[&str]) -> Result<T, ParseError>,
+{
+    indexed_blocks(input)
+        .into_iter()
+        .map(|(start, block)| {
+            parse_block(&block).map_err(|err| {
+                let line = start + err.line;
+                err.on_line(line)
+            })
+        })
+        .collect()
+}
+
+/// Whitespace-separated numbers from a single line.
+pub fn parse_numbers<T>(line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    line.split_whitespace()
+        .map(|num| num.parse().map_err(|err| ParseError::new(line, num, err)))
+        .collect()
+}
+
+/// One row of whitespace-separated numbers per line. Rows can have
+/// different lengths; it's up to the caller to check them if they need to.
+pub fn number_table<T>(input: &str) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    parse_lines(input, parse_numbers)
+}
+
+/// The input as a rectangular grid of characters, one row per line.
+pub fn char_grid(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut width = None;
+    parse_lines(input, |line| {
+        let row: Vec<char> = line.chars().collect();
+        match width {
+            None => width = Some(row.len()),
+            Some(width) if width != row.len() => {
+                let message = format!("expected {} characters but found {}", width, row.len());
+                return Err(ParseError::new(line, line, message));
+            }
+            Some(_) => (),
+        }
+        Ok(row)
+    })
+}
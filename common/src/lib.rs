@@ -0,0 +1,47 @@
+//! Code shared between the individual days' solutions and the runner.
+
+use std::fmt;
+
+pub mod cancel;
+pub mod counter;
+pub mod error;
+pub mod input;
+pub mod logging;
+pub mod params;
+pub mod point;
+pub mod search;
+pub mod visualize;
+
+pub use counter::Counter;
+pub use error::ParseError;
+pub use input::parse_lines;
+pub use logging::Verbosity;
+pub use params::Params;
+pub use point::{Direction, Point, Turn};
+
+/// Interface that every day's solution implements, so that the runner
+/// (and any other tooling) can treat them all the same way.
+///
+/// The raw puzzle input is parsed exactly once, and both parts are then
+/// solved from the parsed representation. Malformed input is reported
+/// through the parser's error rather than by panicking.
+pub trait Solution {
+    /// Parsed form of the puzzle input, shared by both parts.
+    type Input;
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Placeholder answer for a part that hasn't been solved yet.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
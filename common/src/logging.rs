@@ -0,0 +1,128 @@
+//! Leveled diagnostic output for the solutions.
+//!
+//! Days log through the `log` macros (`info!`, `debug!`, `trace!`) rather
+//! than printing directly, so that diagnostics are opt-in. Until a logger
+//! is installed with `init`, everything is discarded, and messages that are
+//! filtered out are never formatted, so logging in hot loops is cheap.
+//!
+//! Roughly: `info` is a handful of lines per run, `debug` is per notable
+//! event (a key found, a robot handing off chips), and `trace` is per
+//! iteration of the inner loops.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_verbosity() {
+        assert_eq!(Ok(Verbosity::Quiet), "quiet".parse());
+        assert_eq!(Ok(Verbosity::Debug), "DEBUG".parse());
+        assert!("loud".parse::<Verbosity>().is_err());
+        for verbosity in Verbosity::ALL.iter() {
+            assert_eq!(Ok(*verbosity), verbosity.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn test_level_filter() {
+        assert_eq!(LevelFilter::Error, Verbosity::Quiet.level_filter());
+        assert_eq!(LevelFilter::Trace, Verbosity::Trace.level_filter());
+        assert!(Verbosity::Info < Verbosity::Debug);
+    }
+}
+
+/// How much diagnostic output to show, from least to most.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only errors.
+    #[default]
+    Quiet,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Verbosity {
+    pub const ALL: [Verbosity; 4] = [
+        Verbosity::Quiet,
+        Verbosity::Info,
+        Verbosity::Debug,
+        Verbosity::Trace,
+    ];
+
+    pub fn level_filter(self) -> LevelFilter {
+        match self {
+            Verbosity::Quiet => LevelFilter::Error,
+            Verbosity::Info => LevelFilter::Info,
+            Verbosity::Debug => LevelFilter::Debug,
+            Verbosity::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Verbosity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Verbosity::Quiet => "quiet",
+            Verbosity::Info => "info",
+            Verbosity::Debug => "debug",
+            Verbosity::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Verbosity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Verbosity, String> {
+        Verbosity::ALL
+            .iter()
+            .find(|verbosity| verbosity.to_string().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("expected one of quiet, info, debug or trace but got {:?}", s))
+    }
+}
+
+// Writes each message to stderr, so that it doesn't get mixed up with the
+// answers on stdout.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let stderr = std::io::stderr();
+            let mut stderr = stderr.lock();
+            let _ = writeln!(
+                stderr,
+                "[{} {}] {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        let _ = std::io::stderr().flush();
+    }
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Installs the stderr logger at the given verbosity. Only the first call
+/// installs the logger; later calls just change the verbosity.
+pub fn init(verbosity: Verbosity) {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(verbosity.level_filter());
+}
@@ -0,0 +1,126 @@
+//! Simple `key = value` format for puzzle inputs made up of several named
+//! parameters rather than a single blob of text, e.g.
+//!
+//! ```text
+//! # The office designer's favorite number
+//! favorite_number = 1364
+//! target = 31,39
+//! ```
+//!
+//! Blank lines and lines starting with `#` are ignored. Values are typed by
+//! whoever reads them, using `FromStr`.
+
+use crate::input::lines;
+use crate::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_get() {
+        let input = "# comment\n\nfavorite_number = 1364\ntarget=31, 39\n";
+        let params = Params::parse(input).unwrap();
+        assert_eq!(Ok(1364), params.get::<u32>("favorite_number"));
+        assert_eq!(Ok(Point::new(31, 39)), params.get::<Point>("target"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let params = Params::parse("favorite_number = 13x4").unwrap();
+        let err = params.get::<u32>("favorite_number").unwrap_err();
+        assert_eq!((1, 19), (err.line, err.column));
+        assert_eq!("13x4", err.text);
+
+        let err = params.get::<u32>("target").unwrap_err();
+        assert_eq!("target", err.text);
+
+        let err = Params::parse("a = 1\nb\n").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+
+        let err = Params::parse("a = 1\na = 2\n").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+
+        let params = Params::parse("a = 1\nb =  -2\n").unwrap();
+        let err = params.error("b", "must not be negative");
+        assert_eq!((2, 6), (err.line, err.column));
+        assert_eq!("-2", err.text);
+        assert_eq!("invalid b: must not be negative", err.message);
+    }
+}
+
+#[derive(Debug)]
+struct Param {
+    key: String,
+    value: String,
+    line: usize,
+    // 1-based column where the value starts
+    column: usize,
+}
+
+#[derive(Debug)]
+pub struct Params {
+    params: Vec<Param>,
+}
+
+impl Params {
+    pub fn parse(input: &str) -> Result<Params, ParseError> {
+        let mut params: Vec<Param> = Vec::new();
+        for (idx, line) in lines(input).into_iter().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let (key, value) = match line.find('=') {
+                Some(eq) => (line[..eq].trim(), line[eq + 1..].trim()),
+                None => {
+                    let err = ParseError::new(line, trimmed, "expected key = value");
+                    return Err(err.on_line(idx + 1));
+                }
+            };
+            if params.iter().any(|param| param.key == key) {
+                let err = ParseError::new(line, key, "duplicate parameter");
+                return Err(err.on_line(idx + 1));
+            }
+            params.push(Param {
+                key: key.to_string(),
+                value: value.to_string(),
+                line: idx + 1,
+                column: ParseError::new(line, value, "").column,
+            });
+        }
+        Ok(Params { params })
+    }
+
+    /// Parses the named parameter, which must be present.
+    pub fn get<T>(&self, key: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let param = self
+            .params
+            .iter()
+            .find(|param| param.key == key)
+            .ok_or_else(|| ParseError::new(key, key, "missing parameter"))?;
+        param.value.parse().map_err(|err| self.error(key, err))
+    }
+
+    /// Error pointing at the named parameter's value, for values that parse
+    /// but still aren't valid. Points at the key instead if it's missing.
+    pub fn error(&self, key: &str, message: impl fmt::Display) -> ParseError {
+        match self.params.iter().find(|param| param.key == key) {
+            Some(param) => ParseError {
+                line: param.line,
+                column: param.column,
+                text: param.value.clone(),
+                message: format!("invalid {}: {}", key, message),
+            },
+            None => ParseError::new(key, key, message),
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! 2D grid coordinates and compass directions.
+//!
+//! North is +y and East is +x, so "up" on a keypad or a map is North. Days
+//! whose grids grow downwards (e.g. 2016 day 13) only care about adjacency,
+//! which doesn't depend on that choice.
+
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::South, Direction::North.reverse());
+        assert_eq!(Direction::North, Direction::West.turn(Turn::Right));
+        for dir in Direction::ALL.iter() {
+            assert_eq!(*dir, dir.turn_left().turn_right());
+            assert_eq!(*dir, dir.reverse().reverse());
+        }
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!(Some(Direction::North), Direction::from_udlr('U'));
+        assert_eq!(Some(Direction::East), Direction::from_udlr('R'));
+        assert_eq!(None, Direction::from_udlr('N'));
+        assert_eq!(Some(Direction::West), Direction::from_compass('W'));
+        assert_eq!(None, Direction::from_compass('U'));
+        assert_eq!(None, Direction::from_compass('x'));
+        assert_eq!(Some(Turn::Left), Turn::from_char('L'));
+        assert_eq!(None, Turn::from_char('U'));
+    }
+
+    #[test]
+    fn test_steps() {
+        let origin = Point::ORIGIN;
+        assert_eq!(Point::new(0, 1), origin.step(Direction::North));
+        assert_eq!(Point::new(-3, 0), origin.moved(Direction::West, 3));
+        assert_eq!(Point::new(2, -1), Point::new(1, 1) + Point::new(1, -2));
+        assert_eq!(Point::new(0, 3), Point::new(1, 1) - Point::new(1, -2));
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(0, Point::ORIGIN.manhattan_distance(Point::ORIGIN));
+        assert_eq!(5, Point::new(2, 3).manhattan_distance(Point::ORIGIN));
+        assert_eq!(12, Point::new(-5, 4).manhattan_distance(Point::new(3, 0)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let pt = Point::new(1, 1);
+        let neighbors4 = pt.neighbors4();
+        assert_eq!(4, neighbors4.len());
+        assert!(neighbors4.iter().all(|nn| nn.manhattan_distance(pt) == 1));
+
+        let neighbors8 = pt.neighbors8();
+        assert_eq!(8, neighbors8.len());
+        assert!(neighbors8.contains(&Point::ORIGIN));
+        assert!(neighbors8.contains(&Point::new(2, 2)));
+        assert!(!neighbors8.contains(&pt));
+    }
+
+    #[test]
+    fn test_in_bounds() {
+        let min = Point::new(-1, -1);
+        let max = Point::new(1, 1);
+        assert!(Point::ORIGIN.in_bounds(min, max));
+        assert!(max.in_bounds(min, max));
+        assert!(!Point::new(2, 0).in_bounds(min, max));
+        assert!(!Point::new(0, -2).in_bounds(min, max));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Ok(Point::new(31, 39)), "31,39".parse());
+        assert_eq!(Ok(Point::new(-1, 2)), " -1 , 2 ".parse());
+        assert!("31".parse::<Point>().is_err());
+        assert!("31,y".parse::<Point>().is_err());
+    }
+}
+
+/// One of the four compass directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// Which way to turn, relative to the current heading.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// All four directions, clockwise starting from North.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Parses a compass heading: N, E, S or W.
+    pub fn from_compass(ch: char) -> Option<Direction> {
+        match ch {
+            'N' => Some(Direction::North),
+            'E' => Some(Direction::East),
+            'S' => Some(Direction::South),
+            'W' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Parses a keypad-style move: U, D, L or R.
+    pub fn from_udlr(ch: char) -> Option<Direction> {
+        match ch {
+            'U' => Some(Direction::North),
+            'R' => Some(Direction::East),
+            'D' => Some(Direction::South),
+            'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_left().turn_left()
+    }
+
+    /// Unit step in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, 1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, -1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+}
+
+impl Turn {
+    pub fn from_char(ch: char) -> Option<Turn> {
+        match ch {
+            'L' => Some(Turn::Left),
+            'R' => Some(Turn::Right),
+            _ => None,
+        }
+    }
+}
+
+/// A location on an integer grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    /// The adjacent point in the given direction.
+    pub fn step(self, dir: Direction) -> Point {
+        self + dir.offset()
+    }
+
+    /// The point `steps` away in the given direction.
+    pub fn moved(self, dir: Direction, steps: i32) -> Point {
+        let offset = dir.offset();
+        Point::new(self.x + steps * offset.x, self.y + steps * offset.y)
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The four points that share an edge with this one, clockwise from North.
+    pub fn neighbors4(self) -> [Point; 4] {
+        let mut neighbors = [self; 4];
+        for (neighbor, dir) in neighbors.iter_mut().zip(Direction::ALL.iter()) {
+            *neighbor = self.step(*dir);
+        }
+        neighbors
+    }
+
+    /// The eight points that share an edge or a corner with this one.
+    pub fn neighbors8(self) -> [Point; 8] {
+        let mut neighbors = [self; 8];
+        let mut idx = 0;
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if dx != 0 || dy != 0 {
+                    neighbors[idx] = Point::new(self.x + dx, self.y + dy);
+                    idx += 1;
+                }
+            }
+        }
+        neighbors
+    }
+
+    /// Whether this point lies in the rectangle spanned by `min` and `max`,
+    /// inclusive on all sides.
+    pub fn in_bounds(self, min: Point, max: Point) -> bool {
+        min.x <= self.x && self.x <= max.x && min.y <= self.y && self.y <= max.y
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+// Points are written as "x,y".
+impl FromStr for Point {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Point, String> {
+        let mut coords = s.split(',').map(|coord| coord.trim().parse::<i32>());
+        match (coords.next(), coords.next(), coords.next()) {
+            (Some(Ok(x)), Some(Ok(y)), None) => Ok(Point::new(x, y)),
+            _ => Err(format!("expected x,y but got {:?}", s)),
+        }
+    }
+}
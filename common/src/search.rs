@@ -0,0 +1,278 @@
+//! Shortest-path searches over an implicit graph.
+//!
+//! The graph is described by a starting state and a function listing each
+//! state's neighbours, so it never has to be built up front (and can be
+//! infinite, as long as the search has a goal or a cost limit to stop it).
+//! All of the searches are A* underneath: `dijkstra` is A* without a
+//! heuristic, and `bfs` is Dijkstra where every step costs one.
+//!
+//! Each search returns a `SearchResult`, holding the cost of reaching every
+//! state that it found and the state that it was reached from, so that the
+//! path can be reconstructed. Searches poll `cancel::check`, so they can be
+//! cut short by the runner's timeout.
+
+use crate::cancel;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    // A number line where each step goes up by one or doubles, with
+    // doubling costing 3.
+    fn steps(n: &u32) -> Vec<(u32, usize)> {
+        vec![(n + 1, 1), (n * 2, 3)]
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(1u32, |n| vec![n + 1, n * 2], |n| *n == 10);
+        assert_eq!(Some(&10), result.goal());
+        assert_eq!(Some(4), result.goal_distance());
+        assert_eq!(Some(vec![1, 2, 4, 5, 10]), result.goal_path());
+        assert_eq!(Some(&4), result.predecessors().get(&5));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra(1u32, steps, |n| *n == 10);
+        assert_eq!(Some(7), result.goal_distance());
+        // Doubling is expensive enough that it's only worth doing once.
+        assert_eq!(Some(vec![1, 2, 3, 4, 5, 10]), result.goal_path());
+        assert_eq!(Some(vec![1, 2]), result.path_to(&2));
+        assert_eq!(None, result.path_to(&1000));
+    }
+
+    #[test]
+    fn test_astar() {
+        let target = 40u32;
+        let heuristic = |n: &u32| if *n < target { 1 } else { 0 };
+        let astar = astar(1u32, steps, heuristic, |n| *n == target);
+        let dijkstra = dijkstra(1u32, steps, |n| *n == target);
+        assert_eq!(dijkstra.goal_distance(), astar.goal_distance());
+        assert_eq!(Some(&1), astar.goal_path().unwrap().first());
+        assert_eq!(Some(&target), astar.goal_path().unwrap().last());
+    }
+
+    #[test]
+    fn test_explore() {
+        let result = explore(0i32, |n| vec![n - 1, n + 1], 3);
+        assert_eq!(None, result.goal());
+        assert_eq!(7, result.distances().len());
+        assert_eq!(Some(3), result.distance(&-3));
+        assert_eq!(None, result.distance(&4));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let result = bfs(0u32, |n| if *n < 5 { vec![n + 1] } else { vec![] }, |n| *n == 6);
+        assert_eq!(None, result.goal_distance());
+        assert_eq!(None, result.goal_path());
+        assert_eq!(6, result.distances().len());
+    }
+}
+
+/// Everything that a search found: the cost of the cheapest path to each
+/// state that it reached, and where that path came from.
+///
+/// A search stops as soon as it reaches its goal, so states that it didn't
+/// get around to expanding might have a cheaper path than the one recorded.
+/// Every state that costs no more than the goal is exact.
+pub struct SearchResult<S> {
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    /// The goal state that was reached, if any.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// The states along the path from the start to the goal, inclusive.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// The state that each reached state (other than the start) was
+    /// reached from.
+    pub fn predecessors(&self) -> &HashMap<S, S> {
+        &self.predecessors
+    }
+
+    /// The states along the path from the start to `state`, inclusive, or
+    /// `None` if it wasn't reached.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// A state waiting to be expanded. The heap pops the lowest estimated total
+// cost first, and ties go to whichever was queued first, so that searches
+// are repeatable.
+struct Queued<S> {
+    estimate: usize,
+    order: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Queued<S>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Queued<S>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Queued<S>) -> Ordering {
+        // BinaryHeap is a max-heap, so flip the ordering.
+        (other.estimate, other.order).cmp(&(self.estimate, self.order))
+    }
+}
+
+fn search<S, N, I, H, G>(start: S, mut neighbors: N, mut heuristic: H, mut is_goal: G, max_cost: Option<usize>) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    result.distances.insert(start.clone(), 0);
+    let mut open = BinaryHeap::new();
+    open.push(Queued {
+        estimate: heuristic(&start),
+        order: 0,
+        cost: 0,
+        state: start,
+    });
+    let mut num_queued = 1;
+    let mut num_expanded = 0;
+
+    while let Some(Queued { cost, state, .. }) = open.pop() {
+        cancel::check();
+        // Rather than updating states that are already queued when a
+        // cheaper path turns up, they're queued again and the stale entry
+        // is skipped here.
+        if result.distances[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+        num_expanded += 1;
+        for (next, step_cost) in neighbors(&state) {
+            let next_cost = cost + step_cost;
+            if max_cost.is_some_and(|max_cost| next_cost > max_cost) {
+                continue;
+            }
+            if result.distances.get(&next).is_some_and(|best| *best <= next_cost) {
+                continue;
+            }
+            result.distances.insert(next.clone(), next_cost);
+            result.predecessors.insert(next.clone(), state.clone());
+            open.push(Queued {
+                estimate: next_cost + heuristic(&next),
+                order: num_queued,
+                cost: next_cost,
+                state: next,
+            });
+            num_queued += 1;
+        }
+    }
+    log::debug!("Expanded {} of {} queued states", num_expanded, num_queued);
+    result
+}
+
+/// Finds the shortest path from `start` to a state satisfying `is_goal`,
+/// where `neighbors` lists each state's neighbours and the cost of moving
+/// there. `heuristic` must never overestimate the remaining cost, or the
+/// path might not be the shortest.
+pub fn astar<S, N, I, H, G>(start: S, neighbors: N, heuristic: H, is_goal: G) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    search(start, neighbors, heuristic, is_goal, None)
+}
+
+/// Finds the shortest path from `start` to a state satisfying `is_goal`,
+/// where `neighbors` lists each state's neighbours and the cost of moving
+/// there.
+pub fn dijkstra<S, N, I, G>(start: S, neighbors: N, is_goal: G) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    G: FnMut(&S) -> bool,
+{
+    search(start, neighbors, |_| 0, is_goal, None)
+}
+
+/// Finds the fewest steps from `start` to a state satisfying `is_goal`,
+/// where `neighbors` lists the states that are one step away from each
+/// state.
+pub fn bfs<S, N, I, G>(start: S, mut neighbors: N, is_goal: G) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let neighbors = |state: &S| neighbors(state).into_iter().map(|next| (next, 1));
+    search(start, neighbors, |_| 0, is_goal, None)
+}
+
+/// Finds every state that can be reached from `start` in at most
+/// `max_steps` steps, where `neighbors` lists the states that are one step
+/// away from each state.
+pub fn explore<S, N, I>(start: S, mut neighbors: N, max_steps: usize) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let neighbors = |state: &S| neighbors(state).into_iter().map(|next| (next, 1));
+    search(start, neighbors, |_| 0, |_| false, Some(max_steps))
+}
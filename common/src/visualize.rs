@@ -0,0 +1,152 @@
+//! Step-by-step animations of how a solution gets to its answer.
+//!
+//! Solutions call `frame` with a closure that draws their current state as
+//! text. Usually nothing is listening and the closure is never called, so
+//! frames are free to leave in. The runner's `--visualize` flag plays them
+//! in the terminal with `animate`, and tests can collect them with `record`.
+//!
+//! Like logging, the animation is drawn on stderr so that it doesn't get
+//! mixed up with the answers on stdout.
+
+use std::cell::RefCell;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn count_to(n: usize) -> usize {
+        for ii in 1..=n {
+            frame(|| format!("{}", ii));
+        }
+        n
+    }
+
+    #[test]
+    fn test_record() {
+        assert!(!is_enabled());
+        let (count, frames) = record(|| count_to(3));
+        assert_eq!(3, count);
+        assert_eq!(vec!["1", "2", "3"], frames);
+        // Nothing is recorded (or drawn) outside of `record`.
+        frame(|| panic!("shouldn't be drawn"));
+    }
+
+    #[test]
+    fn test_nested() {
+        let (_, outer) = record(|| {
+            frame(|| String::from("outer"));
+            let (_, inner) = record(|| count_to(1));
+            assert_eq!(vec!["1"], inner);
+            assert!(is_enabled());
+        });
+        assert_eq!(vec!["outer"], outer);
+    }
+
+    #[test]
+    fn test_reentrant_render() {
+        let (_, frames) = record(|| {
+            frame(|| {
+                assert!(is_enabled());
+                count_to(1);
+                String::from("outer")
+            })
+        });
+        assert_eq!(vec!["1", "outer"], frames);
+    }
+
+    #[test]
+    fn test_terminal_frame() {
+        let mut out = Vec::new();
+        draw(&mut out, "ab\ncd", true).unwrap();
+        assert_eq!("\x1b[2J\x1b[Hab\x1b[K\ncd\x1b[K\n\x1b[J", String::from_utf8(out).unwrap());
+    }
+}
+
+enum Sink {
+    // Draws each frame over the previous one, then waits before carrying on.
+    Terminal { delay: Duration, num_frames: usize },
+    Recorder(Vec<String>),
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+// Moves the cursor back to the top left and draws the frame over the top of
+// the previous one, clearing anything left over from it.
+fn draw(out: &mut impl Write, frame: &str, first: bool) -> std::io::Result<()> {
+    if first {
+        write!(out, "\x1b[2J")?;
+    }
+    write!(out, "\x1b[H")?;
+    for line in frame.lines() {
+        writeln!(out, "{}\x1b[K", line)?;
+    }
+    write!(out, "\x1b[J")?;
+    out.flush()
+}
+
+// Runs `f` with `sink` installed for this thread, handing the sink back
+// afterwards. Whatever was installed before is put back even if `f` panics
+// (or is cancelled).
+fn with_sink<R>(sink: Sink, f: impl FnOnce() -> R) -> (R, Option<Sink>) {
+    struct Restore(Option<Sink>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SINK.with(|sink| *sink.borrow_mut() = previous);
+        }
+    }
+
+    let _restore = Restore(SINK.with(|current| current.replace(Some(sink))));
+    let result = f();
+    let sink = SINK.with(|current| current.borrow_mut().take());
+    (result, sink)
+}
+
+/// Runs `f`, playing its frames in the terminal with `delay` between them.
+pub fn animate<R>(delay: Duration, f: impl FnOnce() -> R) -> R {
+    with_sink(Sink::Terminal { delay, num_frames: 0 }, f).0
+}
+
+/// Runs `f`, returning the frames that it drew along with its result.
+pub fn record<R>(f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    match with_sink(Sink::Recorder(Vec::new()), f) {
+        (result, Some(Sink::Recorder(frames))) => (result, frames),
+        (result, _) => (result, Vec::new()),
+    }
+}
+
+/// Whether anything is watching the frames, for solutions that need to do
+/// extra work to produce them.
+pub fn is_enabled() -> bool {
+    SINK.with(|sink| sink.borrow().is_some())
+}
+
+/// Adds a frame to the animation, if there is one; `render` is only called
+/// when there is.
+pub fn frame(render: impl FnOnce() -> String) {
+    if !is_enabled() {
+        return;
+    }
+    // Rendered before borrowing the sink, so that `render` can check
+    // `is_enabled` or draw frames of its own.
+    let frame = render();
+    SINK.with(|sink| match &mut *sink.borrow_mut() {
+        None => (),
+        Some(Sink::Recorder(frames)) => frames.push(frame),
+        Some(Sink::Terminal { delay, num_frames }) => {
+            let stderr = std::io::stderr();
+            // There's nowhere to report a broken terminal, and it's no
+            // reason to stop solving the puzzle.
+            let _ = draw(&mut stderr.lock(), &frame, *num_frames == 0);
+            *num_frames += 1;
+            thread::sleep(*delay);
+        }
+    })
+}